@@ -3,7 +3,8 @@
 
 extern crate alloc;
 extern crate test;
-use alloc::{format, string::String};
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::Write;
 use test::{black_box, Bencher};
 use unchecked_std::prelude::*;
 
@@ -30,6 +31,15 @@ fn hello_unchecked(name: &str) -> String {
     s
 }
 
+fn hello_unchecked_writer(name: &str) -> String {
+    let len = "Hello, !".len() + name.len();
+    let mut s = String::with_capacity(len);
+    // SAFETY: `s` has been initialized with sufficient capacity
+    let mut writer = unsafe { UncheckedWriter::new(&mut s, len) };
+    write!(writer, "Hello, {name}!").unwrap();
+    s
+}
+
 #[bench]
 fn bench_hello_format(bencher: &mut Bencher) {
     bencher.iter(|| hello_format(black_box("Rust")));
@@ -44,3 +54,167 @@ fn bench_hello_checked(bencher: &mut Bencher) {
 fn bench_hello_unchecked(bencher: &mut Bencher) {
     bencher.iter(|| hello_unchecked(black_box("Rust")));
 }
+
+#[bench]
+fn bench_hello_unchecked_writer(bencher: &mut Bencher) {
+    bencher.iter(|| hello_unchecked_writer(black_box("Rust")));
+}
+
+const TEXT_REPEAT: usize = 64;
+const ASCII_TEXT: &str = "The quick brown fox jumps over the lazy dog, then naps in the sun.";
+const CJK_TEXT: &str =
+    "敏捷的棕色狐狸跳过了懒狗，然后在阳光下打盹。素早い茶色の狐がのろまな犬を飛び越える。";
+const MIXED_TEXT: &str =
+    "Rust 🦀 は速い, ça va? Größe 한국어 ok 👍 naïve café 中文 Ελληνικά done ✨";
+const EMOJI_TEXT: &str = "🦀🚀✨🎉🔥👍🌍🍕🐶🐱🦊🐻🐼🐨🐯🦁🐮🐷🐸🐵🙈🙉🙊🐔🐧🐦🐤🦆🦅🦉";
+
+fn push_chars_checked(text: &str) -> String {
+    let mut s = String::with_capacity(text.len());
+    for ch in text.chars() {
+        s.push(ch);
+    }
+    s
+}
+
+fn push_chars_unchecked(text: &str) -> String {
+    let mut s = String::with_capacity(text.len());
+    for ch in text.chars() {
+        // SAFETY: `s` has been initialized with sufficient capacity
+        unsafe { s.push_unchecked(ch) };
+    }
+    s
+}
+
+/// `push_unchecked` with the UTF-8 bytes computed in a register without
+/// branching on the length and written with one 4-byte store, kept as a
+/// comparison point for the per-byte stores of the crate.
+fn push_chars_branchless_unchecked(text: &str) -> String {
+    let mut s = String::with_capacity(text.len());
+    for ch in text.chars() {
+        let code = ch as u32;
+        let two = 0x80C0 | code >> 6 | (code & 0x3F) << 8;
+        let three = 0x0080_80E0 | code >> 12 | (code >> 6 & 0x3F) << 8 | (code & 0x3F) << 16;
+        let four = 0x8080_80F0
+            | code >> 18
+            | (code >> 12 & 0x3F) << 8
+            | (code >> 6 & 0x3F) << 16
+            | (code & 0x3F) << 24;
+        let encoded = if code < 0x80 {
+            code
+        } else if code < 0x800 {
+            two
+        } else if code < 0x1_0000 {
+            three
+        } else {
+            four
+        };
+        // SAFETY: `s` has been initialized with sufficient capacity, and the
+        // store only touches spare capacity past `ch.len_utf8()` bytes
+        unsafe {
+            let v = s.as_mut_vec();
+            if v.capacity() - v.len() >= 4 {
+                let ptr = v.as_mut_ptr().add(v.len());
+                ptr.cast::<[u8; 4]>().write_unaligned(encoded.to_le_bytes());
+                v.set_len(v.len() + ch.len_utf8());
+            } else {
+                s.push_unchecked(ch);
+            }
+        }
+    }
+    s
+}
+
+#[bench]
+fn bench_push_chars_ascii_checked(bencher: &mut Bencher) {
+    let text = ASCII_TEXT.repeat(TEXT_REPEAT);
+    bencher.iter(|| push_chars_checked(black_box(&text)));
+}
+
+#[bench]
+fn bench_push_chars_ascii_branchless_unchecked(bencher: &mut Bencher) {
+    let text = ASCII_TEXT.repeat(TEXT_REPEAT);
+    bencher.iter(|| push_chars_branchless_unchecked(black_box(&text)));
+}
+
+#[bench]
+fn bench_push_chars_ascii_unchecked(bencher: &mut Bencher) {
+    let text = ASCII_TEXT.repeat(TEXT_REPEAT);
+    bencher.iter(|| push_chars_unchecked(black_box(&text)));
+}
+
+#[bench]
+fn bench_push_chars_cjk_checked(bencher: &mut Bencher) {
+    let text = CJK_TEXT.repeat(TEXT_REPEAT);
+    bencher.iter(|| push_chars_checked(black_box(&text)));
+}
+
+#[bench]
+fn bench_push_chars_cjk_branchless_unchecked(bencher: &mut Bencher) {
+    let text = CJK_TEXT.repeat(TEXT_REPEAT);
+    bencher.iter(|| push_chars_branchless_unchecked(black_box(&text)));
+}
+
+#[bench]
+fn bench_push_chars_cjk_unchecked(bencher: &mut Bencher) {
+    let text = CJK_TEXT.repeat(TEXT_REPEAT);
+    bencher.iter(|| push_chars_unchecked(black_box(&text)));
+}
+
+#[bench]
+fn bench_push_chars_emoji_checked(bencher: &mut Bencher) {
+    let text = EMOJI_TEXT.repeat(TEXT_REPEAT);
+    bencher.iter(|| push_chars_checked(black_box(&text)));
+}
+
+#[bench]
+fn bench_push_chars_emoji_branchless_unchecked(bencher: &mut Bencher) {
+    let text = EMOJI_TEXT.repeat(TEXT_REPEAT);
+    bencher.iter(|| push_chars_branchless_unchecked(black_box(&text)));
+}
+
+#[bench]
+fn bench_push_chars_emoji_unchecked(bencher: &mut Bencher) {
+    let text = EMOJI_TEXT.repeat(TEXT_REPEAT);
+    bencher.iter(|| push_chars_unchecked(black_box(&text)));
+}
+
+#[bench]
+fn bench_push_chars_mixed_checked(bencher: &mut Bencher) {
+    let text = MIXED_TEXT.repeat(TEXT_REPEAT);
+    bencher.iter(|| push_chars_checked(black_box(&text)));
+}
+
+#[bench]
+fn bench_push_chars_mixed_branchless_unchecked(bencher: &mut Bencher) {
+    let text = MIXED_TEXT.repeat(TEXT_REPEAT);
+    bencher.iter(|| push_chars_branchless_unchecked(black_box(&text)));
+}
+
+#[bench]
+fn bench_push_chars_mixed_unchecked(bencher: &mut Bencher) {
+    let text = MIXED_TEXT.repeat(TEXT_REPEAT);
+    bencher.iter(|| push_chars_unchecked(black_box(&text)));
+}
+
+const FILL_LEN: usize = 4096;
+
+#[bench]
+fn bench_fill_u32_checked(bencher: &mut Bencher) {
+    let mut v = Vec::with_capacity(FILL_LEN);
+    bencher.iter(|| {
+        v.clear();
+        v.resize(FILL_LEN, black_box(0xff20_4080_u32));
+        black_box(&v);
+    });
+}
+
+#[bench]
+fn bench_fill_u32_unchecked(bencher: &mut Bencher) {
+    let mut v = Vec::with_capacity(FILL_LEN);
+    bencher.iter(|| {
+        v.clear();
+        // SAFETY: `v` has been initialized with sufficient capacity
+        unsafe { v.push_many_unchecked(black_box(0xff20_4080_u32), FILL_LEN) };
+        black_box(&v);
+    });
+}
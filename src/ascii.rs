@@ -0,0 +1,72 @@
+use super::{generic_string::GenericString, generic_vec::GenericVec};
+
+/// A trait for pushing ASCII into a string without the capacity check.
+pub trait PushAsciiUnchecked {
+    /// Appends the ASCII character `byte` without the capacity check,
+    /// skipping the UTF-8 length dispatch of `push_unchecked`.
+    ///
+    /// # Safety
+    ///
+    /// - `byte` must be ASCII
+    /// - the capacity of the string must be sufficient for 1 new byte
+    unsafe fn push_ascii_unchecked(&mut self, byte: u8);
+
+    /// Appends `string` with ASCII letters mapped to lowercase, like
+    /// `push_str(&string.to_ascii_lowercase())`, in one pass and without the
+    /// capacity check.
+    ///
+    /// Non-ASCII characters are copied unchanged.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the string must be sufficient for the `string.len()`
+    /// new bytes.
+    unsafe fn push_str_ascii_lowercase_unchecked(&mut self, string: &str);
+
+    /// Appends `string` with ASCII letters mapped to uppercase, like
+    /// `push_str(&string.to_ascii_uppercase())`, in one pass and without the
+    /// capacity check.
+    ///
+    /// Non-ASCII characters are copied unchanged.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the string must be sufficient for the `string.len()`
+    /// new bytes.
+    unsafe fn push_str_ascii_uppercase_unchecked(&mut self, string: &str);
+}
+
+impl<S: GenericString> PushAsciiUnchecked for S {
+    #[inline]
+    unsafe fn push_ascii_unchecked(&mut self, byte: u8) {
+        debug_assert!(byte.is_ascii());
+        let v = self.as_mut_vec();
+        debug_assert!(v.len() < v.capacity());
+        *v.as_mut_ptr().add(v.len()) = byte;
+        v.set_len(v.len() + 1);
+    }
+
+    #[inline]
+    unsafe fn push_str_ascii_lowercase_unchecked(&mut self, string: &str) {
+        push_mapped_unchecked(self, string, u8::to_ascii_lowercase);
+    }
+
+    #[inline]
+    unsafe fn push_str_ascii_uppercase_unchecked(&mut self, string: &str) {
+        push_mapped_unchecked(self, string, u8::to_ascii_uppercase);
+    }
+}
+
+/// Appends the bytes of `string` mapped by `f`, which must map ASCII to ASCII
+/// and leave other bytes unchanged.
+#[inline]
+unsafe fn push_mapped_unchecked<S: GenericString>(s: &mut S, string: &str, f: fn(&u8) -> u8) {
+    let v = s.as_mut_vec();
+    let bytes = string.as_bytes();
+    debug_assert!(bytes.len() <= v.capacity() - v.len());
+    let dst = v.as_mut_ptr().add(v.len());
+    for (i, byte) in bytes.iter().enumerate() {
+        *dst.add(i) = f(byte);
+    }
+    v.set_len(v.len() + bytes.len());
+}
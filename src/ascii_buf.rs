@@ -0,0 +1,41 @@
+use super::generic_vec::GenericVec;
+use alloc::{string::String, vec::Vec};
+
+/// A collection of bytes that ASCII can be appended to, which includes both
+/// byte vectors and strings.
+pub trait AsciiBuf {
+    type InnerVec: GenericVec<Item = u8>;
+
+    /// # Safety
+    ///
+    /// Only ASCII bytes may be appended to the returned vector.
+    unsafe fn as_mut_byte_vec(&mut self) -> &mut Self::InnerVec;
+}
+
+impl<V: GenericVec<Item = u8>> AsciiBuf for V {
+    type InnerVec = Self;
+
+    #[inline]
+    unsafe fn as_mut_byte_vec(&mut self) -> &mut Self::InnerVec {
+        self
+    }
+}
+
+impl AsciiBuf for String {
+    type InnerVec = Vec<u8>;
+
+    #[inline]
+    unsafe fn as_mut_byte_vec(&mut self) -> &mut Self::InnerVec {
+        self.as_mut_vec()
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> AsciiBuf for heapless::String<N> {
+    type InnerVec = heapless::Vec<u8, N>;
+
+    #[inline]
+    unsafe fn as_mut_byte_vec(&mut self) -> &mut Self::InnerVec {
+        self.as_mut_vec()
+    }
+}
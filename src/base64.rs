@@ -0,0 +1,232 @@
+use super::{ascii_buf::AsciiBuf, generic_vec::GenericVec};
+
+/// The alphabet and padding of a base64 encoding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Base64Config {
+    /// Whether to use `-` and `_` instead of `+` and `/`.
+    pub url_safe: bool,
+    /// Whether the output length is padded with `=` to a multiple of 4.
+    pub padding: bool,
+}
+
+impl Base64Config {
+    /// The standard alphabet with padding.
+    pub const STANDARD: Self = Self {
+        url_safe: false,
+        padding: true,
+    };
+    /// The standard alphabet without padding.
+    pub const STANDARD_NO_PAD: Self = Self {
+        url_safe: false,
+        padding: false,
+    };
+    /// The URL-safe alphabet with padding.
+    pub const URL_SAFE: Self = Self {
+        url_safe: true,
+        padding: true,
+    };
+    /// The URL-safe alphabet without padding.
+    pub const URL_SAFE_NO_PAD: Self = Self {
+        url_safe: true,
+        padding: false,
+    };
+
+    /// Returns the exact length of `len` bytes encoded as base64.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length overflows `usize`.
+    #[inline]
+    #[must_use]
+    pub const fn encoded_len(self, len: usize) -> usize {
+        let tail = match (len % 3, self.padding) {
+            (0, _) => 0,
+            (_, true) => 4,
+            (rem, false) => rem + 1,
+        };
+        match (len / 3).checked_mul(4) {
+            Some(full) => full + tail,
+            None => panic!("encoded length overflows usize"),
+        }
+    }
+
+    /// Returns an upper bound of the length of `len` bytes of base64 decoded,
+    /// which is exact for unpadded input.
+    #[inline]
+    #[must_use]
+    pub const fn decoded_len_upper_bound(len: usize) -> usize {
+        len / 4 * 3 + len % 4 * 3 / 4
+    }
+
+    #[inline]
+    const fn alphabet(self) -> &'static [u8; 64] {
+        if self.url_safe {
+            URL_SAFE_ALPHABET
+        } else {
+            STANDARD_ALPHABET
+        }
+    }
+
+    #[inline]
+    const fn decode_table(self) -> &'static [u8; 256] {
+        if self.url_safe {
+            &URL_SAFE_DECODE
+        } else {
+            &STANDARD_DECODE
+        }
+    }
+}
+
+/// A trait for base64 encoding without the capacity check.
+pub trait PushBase64Unchecked {
+    /// Appends `bytes` encoded as base64 without the capacity check.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the collection must be sufficient for the
+    /// `config.encoded_len(bytes.len())` new bytes.
+    unsafe fn push_base64_unchecked(&mut self, bytes: &[u8], config: Base64Config);
+}
+
+impl<B: AsciiBuf> PushBase64Unchecked for B {
+    #[inline]
+    unsafe fn push_base64_unchecked(&mut self, bytes: &[u8], config: Base64Config) {
+        let v = self.as_mut_byte_vec();
+        let len = config.encoded_len(bytes.len());
+        debug_assert!(len <= v.capacity() - v.len());
+
+        let alphabet = config.alphabet();
+        let mut dst = v.as_mut_ptr().add(v.len());
+        let chunks = bytes.chunks_exact(3);
+        let rem = chunks.remainder();
+        for chunk in chunks {
+            let n = u32::from(chunk[0]) << 16 | u32::from(chunk[1]) << 8 | u32::from(chunk[2]);
+            dst.cast::<[u8; 4]>().write_unaligned([
+                alphabet[(n >> 18) as usize],
+                alphabet[(n >> 12 & 0x3F) as usize],
+                alphabet[(n >> 6 & 0x3F) as usize],
+                alphabet[(n & 0x3F) as usize],
+            ]);
+            dst = dst.add(4);
+        }
+        match *rem {
+            [a] => {
+                *dst = alphabet[(a >> 2) as usize];
+                *dst.add(1) = alphabet[((a & 0x3) << 4) as usize];
+                if config.padding {
+                    *dst.add(2) = b'=';
+                    *dst.add(3) = b'=';
+                }
+            }
+            [a, b] => {
+                *dst = alphabet[(a >> 2) as usize];
+                *dst.add(1) = alphabet[((a & 0x3) << 4 | b >> 4) as usize];
+                *dst.add(2) = alphabet[((b & 0xF) << 2) as usize];
+                if config.padding {
+                    *dst.add(3) = b'=';
+                }
+            }
+            _ => {}
+        }
+        v.set_len(v.len() + len);
+    }
+}
+
+/// A trait for base64 decoding without the capacity and validity checks.
+pub trait PushBase64DecodedUnchecked {
+    /// Appends the bytes encoded as base64 in `base64` without the capacity
+    /// check and the validity check.
+    ///
+    /// # Safety
+    ///
+    /// - `base64` must be valid base64 for `config`, including the padding
+    ///   and zeroed trailing bits
+    /// - the capacity of the collection must be sufficient for the new
+    ///   bytes, which is guaranteed by
+    ///   `Base64Config::decoded_len_upper_bound(base64.len())` spare bytes
+    unsafe fn push_base64_decoded_unchecked(&mut self, base64: &str, config: Base64Config);
+}
+
+impl<V: GenericVec<Item = u8>> PushBase64DecodedUnchecked for V {
+    #[inline]
+    unsafe fn push_base64_decoded_unchecked(&mut self, base64: &str, config: Base64Config) {
+        debug_assert!(is_valid_base64(base64.as_bytes(), config));
+        let mut input = base64.as_bytes();
+        if config.padding {
+            while let [rest @ .., b'='] = input {
+                input = rest;
+            }
+        }
+        let len = Base64Config::decoded_len_upper_bound(input.len());
+        debug_assert!(len <= self.capacity() - self.len());
+
+        let table = config.decode_table();
+        let mut dst = self.as_mut_ptr().add(self.len());
+        let chunks = input.chunks_exact(4);
+        let rem = chunks.remainder();
+        for chunk in chunks {
+            let n = u32::from(table[chunk[0] as usize]) << 18
+                | u32::from(table[chunk[1] as usize]) << 12
+                | u32::from(table[chunk[2] as usize]) << 6
+                | u32::from(table[chunk[3] as usize]);
+            let [_, b0, b1, b2] = n.to_be_bytes();
+            dst.cast::<[u8; 3]>().write_unaligned([b0, b1, b2]);
+            dst = dst.add(3);
+        }
+        match *rem {
+            [a, b] => {
+                *dst = table[a as usize] << 2 | table[b as usize] >> 4;
+            }
+            [a, b, c] => {
+                *dst = table[a as usize] << 2 | table[b as usize] >> 4;
+                *dst.add(1) = table[b as usize] << 4 | table[c as usize] >> 2;
+            }
+            _ => {}
+        }
+        self.set_len(self.len() + len);
+    }
+}
+
+fn is_valid_base64(input: &[u8], config: Base64Config) -> bool {
+    let table = config.decode_table();
+    let data_len = if config.padding {
+        if !input.len().is_multiple_of(4) {
+            return false;
+        }
+        match input {
+            [.., b'=', b'='] => input.len() - 2,
+            [.., b'='] => input.len() - 1,
+            _ => input.len(),
+        }
+    } else {
+        input.len()
+    };
+    let data = &input[..data_len];
+    let last_bits_zero = match data.len() % 4 {
+        0 => true,
+        1 => false,
+        2 => table[data[data_len - 1] as usize].trailing_zeros() >= 4,
+        _ => table[data[data_len - 1] as usize].trailing_zeros() >= 2,
+    };
+    last_bits_zero && data.iter().all(|&c| table[c as usize] != INVALID)
+}
+
+const STANDARD_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const URL_SAFE_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+const INVALID: u8 = 0xFF;
+
+const fn decode_table(alphabet: &[u8; 64]) -> [u8; 256] {
+    let mut table = [INVALID; 256];
+    let mut i = 0;
+    while i < 64 {
+        table[alphabet[i as usize] as usize] = i;
+        i += 1;
+    }
+    table
+}
+
+static STANDARD_DECODE: [u8; 256] = decode_table(STANDARD_ALPHABET);
+static URL_SAFE_DECODE: [u8; 256] = decode_table(URL_SAFE_ALPHABET);
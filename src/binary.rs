@@ -0,0 +1,66 @@
+use super::generic_vec::GenericVec;
+
+macro_rules! push_binary_unchecked {
+    ($($t:ty: $le:ident $be:ident;)*) => {
+        /// A trait for pushing fixed-width numbers as little- or big-endian
+        /// bytes without the capacity check.
+        pub trait PushBinaryUnchecked {
+            $(
+                #[doc = concat!("Appends `value` as `", stringify!($t), "::to_le_bytes(value)` without the capacity check.")]
+                ///
+                /// # Safety
+                ///
+                #[doc = concat!("The capacity of the collection must be sufficient for the `size_of::<", stringify!($t), ">()` new bytes.")]
+                unsafe fn $le(&mut self, value: $t);
+
+                #[doc = concat!("Appends `value` as `", stringify!($t), "::to_be_bytes(value)` without the capacity check.")]
+                ///
+                /// # Safety
+                ///
+                #[doc = concat!("The capacity of the collection must be sufficient for the `size_of::<", stringify!($t), ">()` new bytes.")]
+                unsafe fn $be(&mut self, value: $t);
+            )*
+        }
+
+        impl<V: GenericVec<Item = u8>> PushBinaryUnchecked for V {
+            $(
+                #[inline]
+                unsafe fn $le(&mut self, value: $t) {
+                    push_bytes_unchecked(self, value.to_le_bytes());
+                }
+
+                #[inline]
+                unsafe fn $be(&mut self, value: $t) {
+                    push_bytes_unchecked(self, value.to_be_bytes());
+                }
+            )*
+        }
+    };
+}
+
+push_binary_unchecked! {
+    u16: push_u16_le_unchecked push_u16_be_unchecked;
+    u32: push_u32_le_unchecked push_u32_be_unchecked;
+    u64: push_u64_le_unchecked push_u64_be_unchecked;
+    u128: push_u128_le_unchecked push_u128_be_unchecked;
+    i16: push_i16_le_unchecked push_i16_be_unchecked;
+    i32: push_i32_le_unchecked push_i32_be_unchecked;
+    i64: push_i64_le_unchecked push_i64_be_unchecked;
+    i128: push_i128_le_unchecked push_i128_be_unchecked;
+    f32: push_f32_le_unchecked push_f32_be_unchecked;
+    f64: push_f64_le_unchecked push_f64_be_unchecked;
+}
+
+/// Appends `bytes` with a single unaligned store.
+#[inline]
+unsafe fn push_bytes_unchecked<V: GenericVec<Item = u8>, const N: usize>(
+    v: &mut V,
+    bytes: [u8; N],
+) {
+    debug_assert!(N <= v.capacity() - v.len());
+    v.as_mut_ptr()
+        .add(v.len())
+        .cast::<[u8; N]>()
+        .write_unaligned(bytes);
+    v.set_len(v.len() + N);
+}
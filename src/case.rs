@@ -0,0 +1,137 @@
+use super::{generic_string::GenericString, generic_vec::GenericVec, write_utf8};
+
+/// An integer bound on the ratio of the length of a lowercased string to the
+/// length of the original string.
+///
+/// The exact worst-case ratio is 3/2, reached with `İ` (2 bytes) mapped to
+/// `i̇` (3 bytes), which this constant rounds up, so `len + len / 2` spare
+/// bytes suffice as well.
+pub const LOWERCASE_MAX_EXPANSION: usize = 2;
+
+/// The maximum ratio of the length of an uppercased string to the length of
+/// the original string, reached with `ΐ` (2 bytes) mapped to `Ϊ́` (6 bytes).
+pub const UPPERCASE_MAX_EXPANSION: usize = 3;
+
+/// The maximum number of bytes written by
+/// [`PushCaseMappedUnchecked::push_lowercase_unchecked`].
+pub const LOWERCASE_CHAR_MAX_LEN: usize = 4;
+
+/// The maximum number of bytes written by
+/// [`PushCaseMappedUnchecked::push_uppercase_unchecked`], reached with `ΐ`
+/// mapped to `Ϊ́`.
+pub const UPPERCASE_CHAR_MAX_LEN: usize = 6;
+
+/// A trait for pushing Unicode case-mapped text into a string without the
+/// capacity check.
+pub trait PushCaseMappedUnchecked {
+    /// Appends the characters of [`char::to_lowercase`] for `ch` without the
+    /// capacity check.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the string must be sufficient for the new bytes, which
+    /// is guaranteed by [`LOWERCASE_CHAR_MAX_LEN`] spare bytes.
+    unsafe fn push_lowercase_unchecked(&mut self, ch: char);
+
+    /// Appends the characters of [`char::to_uppercase`] for `ch` without the
+    /// capacity check.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the string must be sufficient for the new bytes, which
+    /// is guaranteed by [`UPPERCASE_CHAR_MAX_LEN`] spare bytes.
+    unsafe fn push_uppercase_unchecked(&mut self, ch: char);
+
+    /// Appends `string` with every character mapped by
+    /// [`char::to_lowercase`] without the capacity check.
+    ///
+    /// Unlike [`str::to_lowercase`], `Σ` is always mapped to `σ`, even at the
+    /// end of a word.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the string must be sufficient for the new bytes, which
+    /// is guaranteed by `LOWERCASE_MAX_EXPANSION * string.len()` spare bytes.
+    unsafe fn push_str_lowercase_unchecked(&mut self, string: &str);
+
+    /// Appends `string` with every character mapped by
+    /// [`char::to_uppercase`], like [`str::to_uppercase`], without the
+    /// capacity check.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the string must be sufficient for the new bytes, which
+    /// is guaranteed by `UPPERCASE_MAX_EXPANSION * string.len()` spare bytes.
+    unsafe fn push_str_uppercase_unchecked(&mut self, string: &str);
+}
+
+impl<S: GenericString> PushCaseMappedUnchecked for S {
+    #[inline]
+    unsafe fn push_lowercase_unchecked(&mut self, ch: char) {
+        push_mapped_unchecked(self, [ch], u8::to_ascii_lowercase, char::to_lowercase);
+    }
+
+    #[inline]
+    unsafe fn push_uppercase_unchecked(&mut self, ch: char) {
+        push_mapped_unchecked(self, [ch], u8::to_ascii_uppercase, char::to_uppercase);
+    }
+
+    #[inline]
+    unsafe fn push_str_lowercase_unchecked(&mut self, string: &str) {
+        push_mapped_unchecked(
+            self,
+            string.chars(),
+            u8::to_ascii_lowercase,
+            char::to_lowercase,
+        );
+    }
+
+    #[inline]
+    unsafe fn push_str_uppercase_unchecked(&mut self, string: &str) {
+        push_mapped_unchecked(
+            self,
+            string.chars(),
+            u8::to_ascii_uppercase,
+            char::to_uppercase,
+        );
+    }
+}
+
+/// Appends `chars` mapped by `map`, using `map_ascii` for ASCII characters.
+#[inline]
+unsafe fn push_mapped_unchecked<S, I, M>(
+    s: &mut S,
+    chars: I,
+    map_ascii: fn(&u8) -> u8,
+    map: fn(char) -> M,
+) where
+    S: GenericString,
+    I: IntoIterator<Item = char, IntoIter: Clone>,
+    M: Iterator<Item = char>,
+{
+    let chars = chars.into_iter();
+    debug_assert!(
+        chars
+            .clone()
+            .flat_map(map)
+            .map(char::len_utf8)
+            .sum::<usize>()
+            <= s.capacity() - s.len()
+    );
+    let v = s.as_mut_vec();
+    let start = v.as_mut_ptr().add(v.len());
+    let mut dst = start;
+    for ch in chars {
+        if ch.is_ascii() {
+            *dst = map_ascii(&(ch as u8));
+            dst = dst.add(1);
+        } else {
+            for mapped in map(ch) {
+                let len = mapped.len_utf8();
+                write_utf8(mapped, len, dst);
+                dst = dst.add(len);
+            }
+        }
+    }
+    v.set_len(v.len() + dst.offset_from(start).cast_unsigned());
+}
@@ -0,0 +1,113 @@
+use super::{extend_bytes_unchecked, generic_string::GenericString};
+
+/// The maximum ratio of the length of a JSON-escaped string to the length
+/// of the original string, reached with control characters written as
+/// `\u00XX`.
+pub const JSON_ESCAPE_MAX_EXPANSION: usize = 6;
+
+/// The maximum ratio of the length of an HTML-escaped string to the length
+/// of the original string, reached with `"` written as `&quot;`.
+pub const HTML_ESCAPE_MAX_EXPANSION: usize = 6;
+
+/// A trait for pushing escaped strings without the capacity check.
+pub trait PushEscapedUnchecked {
+    /// Appends `string` escaped for a JSON string literal, without the
+    /// surrounding quotes and without the capacity check.
+    ///
+    /// `"`, `\` and control characters are escaped, using the short forms
+    /// such as `\n` where they exist and `\u00XX` otherwise.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the collection must be sufficient for the escaped
+    /// string, which is guaranteed by
+    /// `JSON_ESCAPE_MAX_EXPANSION * string.len()` spare bytes.
+    unsafe fn push_json_escaped_unchecked(&mut self, string: &str);
+
+    /// Appends `string` escaped for HTML or XML text and attribute values
+    /// without the capacity check.
+    ///
+    /// `&`, `<`, `>`, `"` and `'` are escaped as `&amp;`, `&lt;`, `&gt;`,
+    /// `&quot;` and `&#39;`.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the collection must be sufficient for the escaped
+    /// string, which is guaranteed by
+    /// `HTML_ESCAPE_MAX_EXPANSION * string.len()` spare bytes.
+    unsafe fn push_html_escaped_unchecked(&mut self, string: &str);
+}
+
+impl<S: GenericString> PushEscapedUnchecked for S {
+    #[inline]
+    unsafe fn push_json_escaped_unchecked(&mut self, string: &str) {
+        let v = self.as_mut_vec();
+        let bytes = string.as_bytes();
+        let mut start = 0;
+        for (i, &byte) in bytes.iter().enumerate() {
+            let escape = JSON_ESCAPE[byte as usize];
+            if escape == 0 {
+                continue;
+            }
+            extend_bytes_unchecked(v, bytes.get_unchecked(start..i));
+            if escape == b'u' {
+                const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+                extend_bytes_unchecked(
+                    v,
+                    &[
+                        b'\\',
+                        b'u',
+                        b'0',
+                        b'0',
+                        HEX_DIGITS[(byte >> 4) as usize],
+                        HEX_DIGITS[(byte & 0xF) as usize],
+                    ],
+                );
+            } else {
+                extend_bytes_unchecked(v, &[b'\\', escape]);
+            }
+            start = i + 1;
+        }
+        extend_bytes_unchecked(v, bytes.get_unchecked(start..));
+    }
+
+    #[inline]
+    unsafe fn push_html_escaped_unchecked(&mut self, string: &str) {
+        let v = self.as_mut_vec();
+        let bytes = string.as_bytes();
+        let mut start = 0;
+        for (i, &byte) in bytes.iter().enumerate() {
+            let escaped: &[u8] = match byte {
+                b'&' => b"&amp;",
+                b'<' => b"&lt;",
+                b'>' => b"&gt;",
+                b'"' => b"&quot;",
+                b'\'' => b"&#39;",
+                _ => continue,
+            };
+            extend_bytes_unchecked(v, bytes.get_unchecked(start..i));
+            extend_bytes_unchecked(v, escaped);
+            start = i + 1;
+        }
+        extend_bytes_unchecked(v, bytes.get_unchecked(start..));
+    }
+}
+
+/// Maps bytes to the character after `\` in their escape sequence, with `u`
+/// meaning `\u00XX`, or to 0 if they aren't escaped.
+static JSON_ESCAPE: [u8; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 0x20 {
+        table[i] = b'u';
+        i += 1;
+    }
+    table[0x08] = b'b';
+    table[0x0C] = b'f';
+    table[b'\n' as usize] = b'n';
+    table[b'\r' as usize] = b'r';
+    table[b'\t' as usize] = b't';
+    table[b'"' as usize] = b'"';
+    table[b'\\' as usize] = b'\\';
+    table
+};
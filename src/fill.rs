@@ -0,0 +1,156 @@
+use super::{generic_vec::GenericVec, PushManyUnchecked};
+
+/// Fills `count` elements at `dst` with copies of `value`.
+///
+/// Values of 2, 4, 8 or 16 bytes are splatted into a 16-byte pattern that is
+/// stored with [`fill_pattern`]; other sizes double the filled prefix with
+/// `memcpy`.
+///
+/// # Safety
+///
+/// - `dst` must be valid for writes of `count` elements
+/// - `T` must not have padding bytes
+#[inline]
+unsafe fn fill<T: Copy>(dst: *mut T, value: T, count: usize) {
+    if count == 0 {
+        return;
+    }
+    if matches!(size_of::<T>(), 2 | 4 | 8 | 16) {
+        let mut pattern = [0; 16];
+        for i in (0..16).step_by(size_of::<T>()) {
+            pattern
+                .as_mut_ptr()
+                .add(i)
+                .cast::<T>()
+                .write_unaligned(value);
+        }
+        fill_pattern(dst.cast::<u8>(), &pattern, count * size_of::<T>());
+    } else {
+        dst.write(value);
+        let mut filled = 1;
+        while filled < count {
+            let n = filled.min(count - filled);
+            core::ptr::copy_nonoverlapping(dst, dst.add(filled), n);
+            filled += n;
+        }
+    }
+}
+
+/// Repeats `pattern` over `len` bytes at `dst`, ending with a prefix of it.
+///
+/// On x86 and x86-64, 32-byte AVX2 stores are used if the CPU supports them,
+/// which is detected at runtime with the `std` feature and at compile time
+/// otherwise. The fallback stores 16 bytes at a time, which LLVM lowers to
+/// SSE2 or NEON stores where the target has them.
+#[inline]
+unsafe fn fill_pattern(dst: *mut u8, pattern: &[u8; 16], len: usize) {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    if len >= 64 && has_avx2() {
+        fill_pattern_avx2(dst, pattern, len);
+        return;
+    }
+    fill_pattern_scalar(dst, pattern, len);
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[inline]
+#[allow(clippy::missing_const_for_fn)] // runtime detection isn't const
+fn has_avx2() -> bool {
+    #[cfg(feature = "std")]
+    {
+        std::is_x86_feature_detected!("avx2")
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        cfg!(target_feature = "avx2")
+    }
+}
+
+/// [`fill_pattern`] with 32-byte AVX2 stores. `len` must be at least 32.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "avx2")]
+unsafe fn fill_pattern_avx2(dst: *mut u8, pattern: &[u8; 16], len: usize) {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::{
+        _mm256_broadcastsi128_si256, _mm256_store_si256, _mm256_storeu_si256, _mm_loadu_si128,
+    };
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::{
+        _mm256_broadcastsi128_si256, _mm256_store_si256, _mm256_storeu_si256, _mm_loadu_si128,
+    };
+
+    // An unaligned store covers the bytes before the first 32-byte boundary,
+    // after which the pattern continues rotated by their count.
+    let head = dst.align_offset(32);
+    _mm256_storeu_si256(
+        dst.cast(),
+        _mm256_broadcastsi128_si256(_mm_loadu_si128(pattern.as_ptr().cast())),
+    );
+    let mut doubled = [0; 32];
+    doubled[..16].copy_from_slice(pattern);
+    doubled[16..].copy_from_slice(pattern);
+    let rotated = doubled.as_ptr().add(head % 16).cast::<[u8; 16]>().read();
+    let vector = _mm256_broadcastsi128_si256(_mm_loadu_si128(rotated.as_ptr().cast()));
+
+    let mut offset = head;
+    while len - offset >= 128 {
+        _mm256_store_si256(dst.add(offset).cast(), vector);
+        _mm256_store_si256(dst.add(offset + 32).cast(), vector);
+        _mm256_store_si256(dst.add(offset + 64).cast(), vector);
+        _mm256_store_si256(dst.add(offset + 96).cast(), vector);
+        offset += 128;
+    }
+    while len - offset >= 32 {
+        _mm256_store_si256(dst.add(offset).cast(), vector);
+        offset += 32;
+    }
+    fill_pattern_scalar(dst.add(offset), &rotated, len - offset);
+}
+
+#[inline]
+const unsafe fn fill_pattern_scalar(dst: *mut u8, pattern: &[u8; 16], len: usize) {
+    let mut offset = 0;
+    while len - offset >= 16 {
+        dst.add(offset).cast::<[u8; 16]>().write_unaligned(*pattern);
+        offset += 16;
+    }
+    core::ptr::copy_nonoverlapping(pattern.as_ptr(), dst.add(offset), len - offset);
+}
+
+macro_rules! impl_push_many_unchecked {
+    ($($t:ty)*) => ($(
+        impl<V: GenericVec<Item = $t>> PushManyUnchecked<$t> for V {
+            /// Appends `value` `count` times to the back of the vector without
+            /// the capacity check.
+            ///
+            /// # Safety
+            ///
+            /// The capacity of the vector must be sufficient for the new items.
+            #[inline]
+            unsafe fn push_many_unchecked(&mut self, value: $t, count: usize) {
+                debug_assert!(self.capacity() - self.len() >= count);
+
+                fill(self.as_mut_ptr().add(self.len()), value, count);
+                self.set_len(self.len() + count);
+            }
+        }
+    )*)
+}
+
+impl_push_many_unchecked! { u16 u32 u64 u128 usize i16 i32 i64 i128 isize f32 f64 char }
+
+impl<V: GenericVec<Item = [u8; N]>, const N: usize> PushManyUnchecked<[u8; N]> for V {
+    /// Appends `value` `count` times to the back of the vector without the
+    /// capacity check.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the vector must be sufficient for the new items.
+    #[inline]
+    unsafe fn push_many_unchecked(&mut self, value: [u8; N], count: usize) {
+        debug_assert!(self.capacity() - self.len() >= count);
+
+        fill(self.as_mut_ptr().add(self.len()), value, count);
+        self.set_len(self.len() + count);
+    }
+}
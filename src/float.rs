@@ -0,0 +1,524 @@
+//! Shortest round-trip float formatting based on Ryū by Ulf Adams.
+//!
+//! The conversion to decimal digits (`d2d`, `f2d` and their helpers) and the
+//! tables in `tables.rs` are derived from the reference implementation at
+//! <https://github.com/ulfjack/ryu>, Copyright 2018 Ulf Adams, used under the
+//! Boost Software License 1.0, whose text is in `LICENSE-BOOST` at the root
+//! of this crate.
+
+use self::tables::{
+    DOUBLE_POW5_INV_SPLIT, DOUBLE_POW5_SPLIT, FLOAT_POW5_INV_SPLIT, FLOAT_POW5_SPLIT,
+};
+use super::{
+    ascii_buf::AsciiBuf,
+    generic_vec::GenericVec,
+    int::{write_u64_digits, Digits},
+};
+
+mod tables;
+
+/// The maximum number of bytes written by
+/// [`PushFloatUnchecked::push_f32_unchecked`].
+pub const F32_MAX_LEN: usize = 19;
+
+/// The maximum number of bytes written by
+/// [`PushFloatUnchecked::push_f64_unchecked`].
+pub const F64_MAX_LEN: usize = 24;
+
+/// A trait for pushing floats as ASCII text without the capacity check.
+pub trait PushFloatUnchecked {
+    /// Appends the shortest representation of `value` that round-trips, as
+    /// formatted by `{:?}`, without the capacity check.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the collection must be sufficient for the new bytes,
+    /// which is guaranteed by [`F32_MAX_LEN`] spare bytes.
+    unsafe fn push_f32_unchecked(&mut self, value: f32);
+
+    /// Appends the shortest representation of `value` that round-trips, as
+    /// formatted by `{:?}`, without the capacity check.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the collection must be sufficient for the new bytes,
+    /// which is guaranteed by [`F64_MAX_LEN`] spare bytes.
+    unsafe fn push_f64_unchecked(&mut self, value: f64);
+}
+
+impl<B: AsciiBuf> PushFloatUnchecked for B {
+    #[inline]
+    unsafe fn push_f32_unchecked(&mut self, value: f32) {
+        let v = self.as_mut_byte_vec();
+        let dst = v.as_mut_ptr().add(v.len());
+        let len = if value.is_finite() && value != 0.0 {
+            let bits = value.to_bits();
+            let (digits, exp) = f2d(
+                bits & ((1 << FLOAT_MANTISSA_BITS) - 1),
+                bits >> FLOAT_MANTISSA_BITS & ((1 << FLOAT_EXPONENT_BITS) - 1),
+            );
+            let negative = value.is_sign_negative();
+            debug_assert!(shortest_len(negative, u64::from(digits), exp) <= v.capacity() - v.len());
+            write_shortest(dst, negative, u64::from(digits), exp)
+        } else {
+            let special = special_str(value.is_sign_negative(), value.is_nan(), value == 0.0);
+            debug_assert!(special.len() <= v.capacity() - v.len());
+            core::ptr::copy_nonoverlapping(special.as_ptr(), dst, special.len());
+            special.len()
+        };
+        v.set_len(v.len() + len);
+    }
+
+    #[inline]
+    unsafe fn push_f64_unchecked(&mut self, value: f64) {
+        let v = self.as_mut_byte_vec();
+        let dst = v.as_mut_ptr().add(v.len());
+        let len = if value.is_finite() && value != 0.0 {
+            let bits = value.to_bits();
+            let (digits, exp) = d2d(
+                bits & ((1 << DOUBLE_MANTISSA_BITS) - 1),
+                (bits >> DOUBLE_MANTISSA_BITS) as u32 & ((1 << DOUBLE_EXPONENT_BITS) - 1),
+            );
+            let negative = value.is_sign_negative();
+            debug_assert!(shortest_len(negative, digits, exp) <= v.capacity() - v.len());
+            write_shortest(dst, negative, digits, exp)
+        } else {
+            let special = special_str(value.is_sign_negative(), value.is_nan(), value == 0.0);
+            debug_assert!(special.len() <= v.capacity() - v.len());
+            core::ptr::copy_nonoverlapping(special.as_ptr(), dst, special.len());
+            special.len()
+        };
+        v.set_len(v.len() + len);
+    }
+}
+
+/// NaN, an infinity or a zero as formatted by `{:?}`.
+#[inline]
+const fn special_str(negative: bool, nan: bool, zero: bool) -> &'static [u8] {
+    match (nan, zero, negative) {
+        (true, _, _) => b"NaN",
+        (false, true, false) => b"0.0",
+        (false, true, true) => b"-0.0",
+        (false, false, false) => b"inf",
+        (false, false, true) => b"-inf",
+    }
+}
+
+/// The number of bytes written by [`write_shortest`].
+#[inline]
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+fn shortest_len(negative: bool, digits: u64, exp: i32) -> usize {
+    let n_digits = digits.decimal_len();
+    let sci_exp = exp + n_digits as i32 - 1;
+    let len = if !(-4..16).contains(&sci_exp) {
+        n_digits
+            + usize::from(n_digits > 1)
+            + 1
+            + usize::from(sci_exp < 0)
+            + u64::from(sci_exp.unsigned_abs()).decimal_len()
+    } else if sci_exp < 0 {
+        1 + sci_exp.unsigned_abs() as usize + n_digits
+    } else if n_digits as i32 <= sci_exp + 1 {
+        sci_exp.unsigned_abs() as usize + 3
+    } else {
+        n_digits + 1
+    };
+    usize::from(negative) + len
+}
+
+/// Writes `digits * 10^exp` the way `{:?}` does and returns the number of
+/// bytes written.
+#[inline]
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_sign_loss
+)]
+unsafe fn write_shortest(mut dst: *mut u8, negative: bool, digits: u64, exp: i32) -> usize {
+    let start = dst;
+    if negative {
+        *dst = b'-';
+        dst = dst.add(1);
+    }
+    let n_digits = digits.decimal_len();
+    let sci_exp = exp + n_digits as i32 - 1;
+
+    if !(-4..16).contains(&sci_exp) {
+        // d[.ddd]e[-]x
+        write_u64_digits(digits, dst.add(1 + n_digits));
+        *dst = *dst.add(1);
+        if n_digits > 1 {
+            *dst.add(1) = b'.';
+            dst = dst.add(1 + n_digits);
+        } else {
+            dst = dst.add(1);
+        }
+        *dst = b'e';
+        dst = dst.add(1);
+        if sci_exp < 0 {
+            *dst = b'-';
+            dst = dst.add(1);
+        }
+        let abs_exp = u64::from(sci_exp.unsigned_abs());
+        dst = dst.add(abs_exp.decimal_len());
+        write_u64_digits(abs_exp, dst);
+    } else if sci_exp < 0 {
+        // 0.000ddd
+        let n_zeros = (-sci_exp) as usize - 1;
+        core::ptr::copy_nonoverlapping(b"0.000".as_ptr(), dst, 2 + n_zeros);
+        dst = dst.add(2 + n_zeros + n_digits);
+        write_u64_digits(digits, dst);
+    } else if n_digits as i32 <= sci_exp + 1 {
+        // ddd000.0
+        let n_zeros = (sci_exp + 1) as usize - n_digits;
+        write_u64_digits(digits, dst.add(n_digits));
+        dst = dst.add(n_digits);
+        core::ptr::write_bytes(dst, b'0', n_zeros);
+        dst = dst.add(n_zeros);
+        core::ptr::copy_nonoverlapping(b".0".as_ptr(), dst, 2);
+        dst = dst.add(2);
+    } else {
+        // ddd.ddd
+        let int_len = (sci_exp + 1) as usize;
+        write_u64_digits(digits, dst.add(1 + n_digits));
+        core::ptr::copy(dst.add(1), dst, int_len);
+        *dst.add(int_len) = b'.';
+        dst = dst.add(1 + n_digits);
+    }
+    dst.offset_from(start).cast_unsigned()
+}
+
+const DOUBLE_MANTISSA_BITS: u32 = 52;
+const DOUBLE_EXPONENT_BITS: u32 = 11;
+const DOUBLE_BIAS: i32 = 1023;
+const DOUBLE_POW5_INV_BITCOUNT: i32 = 125;
+const DOUBLE_POW5_BITCOUNT: i32 = 125;
+
+const FLOAT_MANTISSA_BITS: u32 = 23;
+const FLOAT_EXPONENT_BITS: u32 = 8;
+const FLOAT_BIAS: i32 = 127;
+const FLOAT_POW5_INV_BITCOUNT: i32 = 59;
+const FLOAT_POW5_BITCOUNT: i32 = 61;
+
+/// Returns `ceil(log2(5^e))` for `e` in `1..=3528`, and 1 for `e == 0`.
+#[inline]
+#[allow(clippy::cast_sign_loss, clippy::cast_possible_wrap)]
+const fn pow5bits(e: i32) -> i32 {
+    ((e as u32 * 1_217_359) >> 19) as i32 + 1
+}
+
+/// Returns `floor(log10(2^e))` for `e` in `0..=1650`.
+#[inline]
+#[allow(clippy::cast_sign_loss)]
+const fn log10_pow2(e: i32) -> u32 {
+    (e as u32 * 78_913) >> 18
+}
+
+/// Returns `floor(log10(5^e))` for `e` in `0..=2620`.
+#[inline]
+#[allow(clippy::cast_sign_loss)]
+const fn log10_pow5(e: i32) -> u32 {
+    (e as u32 * 732_923) >> 20
+}
+
+#[inline]
+const fn pow5_factor(mut value: u64) -> u32 {
+    let mut count = 0;
+    while value.is_multiple_of(5) {
+        value /= 5;
+        count += 1;
+    }
+    count
+}
+
+#[inline]
+const fn multiple_of_power_of_5(value: u64, p: u32) -> bool {
+    pow5_factor(value) >= p
+}
+
+#[inline]
+const fn multiple_of_power_of_2(value: u64, p: u32) -> bool {
+    value & ((1 << p) - 1) == 0
+}
+
+#[inline]
+#[allow(clippy::cast_possible_truncation)]
+const fn mul_shift_64(m: u64, mul: u128, j: u32) -> u64 {
+    let b0 = m as u128 * (mul as u64) as u128;
+    let b2 = m as u128 * (mul >> 64);
+    (((b0 >> 64) + b2) >> (j - 64)) as u64
+}
+
+/// Returns the decimal digits and exponent of the shortest representation
+/// of a nonzero finite `f64`.
+#[inline]
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_sign_loss,
+    clippy::similar_names,
+    clippy::too_many_lines
+)]
+fn d2d(ieee_mantissa: u64, ieee_exponent: u32) -> (u64, i32) {
+    let (e2, m2) = if ieee_exponent == 0 {
+        (
+            1 - DOUBLE_BIAS - DOUBLE_MANTISSA_BITS as i32 - 2,
+            ieee_mantissa,
+        )
+    } else {
+        (
+            ieee_exponent as i32 - DOUBLE_BIAS - DOUBLE_MANTISSA_BITS as i32 - 2,
+            (1 << DOUBLE_MANTISSA_BITS) | ieee_mantissa,
+        )
+    };
+    let accept_bounds = m2 & 1 == 0;
+
+    // step 2: determine the interval of valid decimal representations
+    let mv = 4 * m2;
+    let mm_shift = u64::from(ieee_mantissa != 0 || ieee_exponent <= 1);
+
+    // step 3: convert to a decimal power base using 128-bit arithmetic
+    let mut vr;
+    let mut vp;
+    let mut vm;
+    let e10;
+    let mut vm_is_trailing_zeros = false;
+    let mut vr_is_trailing_zeros = false;
+    if e2 >= 0 {
+        let q = log10_pow2(e2) - u32::from(e2 > 3);
+        e10 = q as i32;
+        let k = DOUBLE_POW5_INV_BITCOUNT + pow5bits(q as i32) - 1;
+        let i = -e2 + q as i32 + k;
+        let mul = DOUBLE_POW5_INV_SPLIT[q as usize];
+        vr = mul_shift_64(4 * m2, mul, i as u32);
+        vp = mul_shift_64(4 * m2 + 2, mul, i as u32);
+        vm = mul_shift_64(4 * m2 - 1 - mm_shift, mul, i as u32);
+        if q <= 21 {
+            // only one of mp, mv and mm can be a multiple of 5, if any
+            if mv % 5 == 0 {
+                vr_is_trailing_zeros = multiple_of_power_of_5(mv, q);
+            } else if accept_bounds {
+                vm_is_trailing_zeros = multiple_of_power_of_5(mv - 1 - mm_shift, q);
+            } else {
+                vp -= u64::from(multiple_of_power_of_5(mv + 2, q));
+            }
+        }
+    } else {
+        let q = log10_pow5(-e2) - u32::from(-e2 > 1);
+        e10 = q as i32 + e2;
+        let i = -e2 - q as i32;
+        let k = pow5bits(i) - DOUBLE_POW5_BITCOUNT;
+        let j = q as i32 - k;
+        let mul = DOUBLE_POW5_SPLIT[i as usize];
+        vr = mul_shift_64(4 * m2, mul, j as u32);
+        vp = mul_shift_64(4 * m2 + 2, mul, j as u32);
+        vm = mul_shift_64(4 * m2 - 1 - mm_shift, mul, j as u32);
+        if q <= 1 {
+            // mv has at least q trailing zero bits, as it is `4 * m2`
+            vr_is_trailing_zeros = true;
+            if accept_bounds {
+                vm_is_trailing_zeros = mm_shift == 1;
+            } else {
+                vp -= 1;
+            }
+        } else if q < 63 {
+            vr_is_trailing_zeros = multiple_of_power_of_2(mv, q);
+        }
+    }
+
+    // step 4: find the shortest decimal representation in the interval
+    let mut removed = 0;
+    let mut last_removed_digit = 0;
+    let output = if vm_is_trailing_zeros || vr_is_trailing_zeros {
+        // the general case, which happens rarely
+        while vp / 10 > vm / 10 {
+            vm_is_trailing_zeros &= vm % 10 == 0;
+            last_removed_digit = vr % 10;
+            vr /= 10;
+            vp /= 10;
+            vm /= 10;
+            removed += 1;
+        }
+        if vm_is_trailing_zeros {
+            while vm % 10 == 0 {
+                last_removed_digit = vr % 10;
+                vr /= 10;
+                vm /= 10;
+                removed += 1;
+            }
+        }
+        // unlike the reference implementation, which rounds an exact
+        // .....50..0 to even, round it up like `core::fmt` does
+        vr + u64::from(
+            (vr == vm && (!accept_bounds || !vm_is_trailing_zeros)) || last_removed_digit >= 5,
+        )
+    } else {
+        // the specialized common case
+        let mut round_up = false;
+        if vp / 100 > vm / 100 {
+            round_up = vr % 100 >= 50;
+            vr /= 100;
+            vp /= 100;
+            vm /= 100;
+            removed += 2;
+        }
+        while vp / 10 > vm / 10 {
+            round_up = vr % 10 >= 5;
+            vr /= 10;
+            vp /= 10;
+            vm /= 10;
+            removed += 1;
+        }
+        vr + u64::from(vr == vm || round_up)
+    };
+    (output, e10 + removed)
+}
+
+#[inline]
+#[allow(clippy::cast_possible_truncation)]
+const fn mul_shift_32(m: u32, factor: u64, shift: i32) -> u32 {
+    let factor_lo = factor as u32;
+    let factor_hi = (factor >> 32) as u32;
+    let bits0 = m as u64 * factor_lo as u64;
+    let bits1 = m as u64 * factor_hi as u64;
+    let sum = (bits0 >> 32) + bits1;
+    (sum >> (shift - 32)) as u32
+}
+
+#[inline]
+#[allow(clippy::cast_sign_loss)]
+const fn mul_pow5_inv_div_pow2(m: u32, q: u32, j: i32) -> u32 {
+    mul_shift_32(m, FLOAT_POW5_INV_SPLIT[q as usize], j)
+}
+
+#[inline]
+#[allow(clippy::cast_sign_loss)]
+const fn mul_pow5_div_pow2(m: u32, i: i32, j: i32) -> u32 {
+    mul_shift_32(m, FLOAT_POW5_SPLIT[i as usize], j)
+}
+
+/// Returns the decimal digits and exponent of the shortest representation
+/// of a nonzero finite `f32`.
+#[inline]
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_sign_loss,
+    clippy::similar_names,
+    clippy::too_many_lines
+)]
+fn f2d(ieee_mantissa: u32, ieee_exponent: u32) -> (u32, i32) {
+    let (e2, m2) = if ieee_exponent == 0 {
+        (
+            1 - FLOAT_BIAS - FLOAT_MANTISSA_BITS as i32 - 2,
+            ieee_mantissa,
+        )
+    } else {
+        (
+            ieee_exponent as i32 - FLOAT_BIAS - FLOAT_MANTISSA_BITS as i32 - 2,
+            (1 << FLOAT_MANTISSA_BITS) | ieee_mantissa,
+        )
+    };
+    let accept_bounds = m2 & 1 == 0;
+
+    // step 2: determine the interval of valid decimal representations
+    let mv = 4 * m2;
+    let mp = 4 * m2 + 2;
+    let mm_shift = u32::from(ieee_mantissa != 0 || ieee_exponent <= 1);
+    let mm = 4 * m2 - 1 - mm_shift;
+
+    // step 3: convert to a decimal power base using 64-bit arithmetic
+    let mut vr;
+    let mut vp;
+    let mut vm;
+    let e10;
+    let mut vm_is_trailing_zeros = false;
+    let mut vr_is_trailing_zeros = false;
+    let mut last_removed_digit = 0;
+    if e2 >= 0 {
+        let q = log10_pow2(e2);
+        e10 = q as i32;
+        let k = FLOAT_POW5_INV_BITCOUNT + pow5bits(q as i32) - 1;
+        let i = -e2 + q as i32 + k;
+        vr = mul_pow5_inv_div_pow2(mv, q, i);
+        vp = mul_pow5_inv_div_pow2(mp, q, i);
+        vm = mul_pow5_inv_div_pow2(mm, q, i);
+        if q != 0 && (vp - 1) / 10 <= vm / 10 {
+            // one removed digit is needed even if the loop below won't run
+            let l = FLOAT_POW5_INV_BITCOUNT + pow5bits(q as i32 - 1) - 1;
+            last_removed_digit = mul_pow5_inv_div_pow2(mv, q - 1, -e2 + q as i32 - 1 + l) % 10;
+        }
+        if q <= 9 {
+            // only one of mp, mv and mm can be a multiple of 5, if any
+            if mv % 5 == 0 {
+                vr_is_trailing_zeros = multiple_of_power_of_5(u64::from(mv), q);
+            } else if accept_bounds {
+                vm_is_trailing_zeros = multiple_of_power_of_5(u64::from(mm), q);
+            } else {
+                vp -= u32::from(multiple_of_power_of_5(u64::from(mp), q));
+            }
+        }
+    } else {
+        let q = log10_pow5(-e2);
+        e10 = q as i32 + e2;
+        let i = -e2 - q as i32;
+        let k = pow5bits(i) - FLOAT_POW5_BITCOUNT;
+        let j = q as i32 - k;
+        vr = mul_pow5_div_pow2(mv, i, j);
+        vp = mul_pow5_div_pow2(mp, i, j);
+        vm = mul_pow5_div_pow2(mm, i, j);
+        if q != 0 && (vp - 1) / 10 <= vm / 10 {
+            let j = q as i32 - 1 - (pow5bits(i + 1) - FLOAT_POW5_BITCOUNT);
+            last_removed_digit = mul_pow5_div_pow2(mv, i + 1, j) % 10;
+        }
+        if q <= 1 {
+            // mv has at least q trailing zero bits, as it is `4 * m2`
+            vr_is_trailing_zeros = true;
+            if accept_bounds {
+                vm_is_trailing_zeros = mm_shift == 1;
+            } else {
+                vp -= 1;
+            }
+        } else if q < 31 {
+            vr_is_trailing_zeros = multiple_of_power_of_2(u64::from(mv), q - 1);
+        }
+    }
+
+    // step 4: find the shortest decimal representation in the interval
+    let mut removed = 0;
+    let output = if vm_is_trailing_zeros || vr_is_trailing_zeros {
+        // the general case, which happens rarely
+        while vp / 10 > vm / 10 {
+            vm_is_trailing_zeros &= vm % 10 == 0;
+            last_removed_digit = vr % 10;
+            vr /= 10;
+            vp /= 10;
+            vm /= 10;
+            removed += 1;
+        }
+        if vm_is_trailing_zeros {
+            while vm % 10 == 0 {
+                last_removed_digit = vr % 10;
+                vr /= 10;
+                vm /= 10;
+                removed += 1;
+            }
+        }
+        // unlike the reference implementation, which rounds an exact
+        // .....50..0 to even, round it up like `core::fmt` does
+        vr + u32::from(
+            (vr == vm && (!accept_bounds || !vm_is_trailing_zeros)) || last_removed_digit >= 5,
+        )
+    } else {
+        // the specialized common case
+        while vp / 10 > vm / 10 {
+            last_removed_digit = vr % 10;
+            vr /= 10;
+            vp /= 10;
+            vm /= 10;
+            removed += 1;
+        }
+        vr + u32::from(vr == vm || last_removed_digit >= 5)
+    };
+    (output, e10 + removed)
+}
@@ -0,0 +1,768 @@
+// Derived from Ryū <https://github.com/ulfjack/ryu>, Copyright 2018 Ulf Adams,
+// used under the Boost Software License 1.0 (see `LICENSE-BOOST`).
+//
+// Generated by computing the following with arbitrary precision integers,
+// where `bit_len(5^i)` is `pow5bits(i)`:
+//
+// - `DOUBLE_POW5_INV_SPLIT[i] = 2^(bit_len(5^i) - 1 + 125) / 5^i + 1`
+// - `DOUBLE_POW5_SPLIT[i] = 5^i` normalized to 125 bits
+// - `FLOAT_POW5_INV_SPLIT[i] = 2^(bit_len(5^i) - 1 + 59) / 5^i + 1`
+// - `FLOAT_POW5_SPLIT[i] = 5^i` normalized to 61 bits
+
+pub const DOUBLE_POW5_INV_SPLIT: [u128; 342] = [
+    0x2000_0000_0000_0000_0000_0000_0000_0001,
+    0x1999_9999_9999_9999_9999_9999_9999_999a,
+    0x147a_e147_ae14_7ae1_47ae_147a_e147_ae15,
+    0x1062_4dd2_f1a9_fbe7_6c8b_4395_8106_24de,
+    0x1a36_e2eb_1c43_2ca5_7a78_6c22_6809_d496,
+    0x14f8_b588_e368_f084_61f9_f01b_866e_43ab,
+    0x10c6_f7a0_b5ed_8d36_b4c7_f349_3858_3622,
+    0x1ad7_f29a_bcaf_4857_87a6_520e_c08d_236a,
+    0x1579_8ee2_308c_39df_9fb8_41a5_66d7_4f88,
+    0x112e_0be8_26d6_94b2_e62d_0151_1f12_a607,
+    0x1b7c_dfd9_d7bd_bab7_d6ae_6881_cb51_09a4,
+    0x15fd_7fe1_7964_955f_def1_ed34_a2a7_3aea,
+    0x1197_9981_2dea_1119_7f27_f0f6_e885_c8bb,
+    0x1c25_c268_4976_81c2_650c_b4be_40d6_0df8,
+    0x1684_9b86_a12b_9b01_ea70_9098_33de_7193,
+    0x1203_af9e_e756_159b_21f3_a6e0_297e_c143,
+    0x1cd2_b297_d889_bc2b_6985_d7cd_0f31_3537,
+    0x170e_f546_46d4_9689_2137_dfd7_3f5a_90f9,
+    0x1272_5dd1_d243_aba0_e75f_e645_cc48_73fa,
+    0x1d83_c94f_b6d2_ac34_a566_3d3c_7a0d_865d,
+    0x179c_a10c_9242_235d_511e_9763_94d7_9eb1,
+    0x12e3_b40a_0e9b_4f7d_da7e_df82_dd79_4bc1,
+    0x1e39_2010_175e_e596_2a64_98d1_625b_ac68,
+    0x182d_b340_12b2_5144_eeb6_e0a7_81e2_f053,
+    0x1357_c299_a88e_a76a_5892_4d52_ce4f_26a9,
+    0x1ef2_d0f5_da7d_d8aa_2750_7bb7_b07e_a441,
+    0x18c2_40c4_aecb_13bb_52a6_c95f_c065_5034,
+    0x13ce_9a36_f23c_0fc9_0eeb_d44c_99ea_a690,
+    0x1fb0_f6be_5060_1941_b179_53ad_c311_0a80,
+    0x195a_5efe_a6b3_4767_c12d_dc8b_0274_0867,
+    0x1448_4bfe_ebc2_9f86_3424_b06f_3529_a052,
+    0x1039_d665_8968_7f9e_901d_59f2_90ee_19db,
+    0x19f6_23d5_a8a7_3297_4cfb_c31d_b4b0_295f,
+    0x14c4_e977_ba1f_5bac_3d96_35b1_5d59_bab2,
+    0x109d_8792_fb4c_4956_97ab_5e27_7de1_6228,
+    0x1a95_a5b7_f87a_0ef0_f2ab_c9d8_c968_9d0d,
+    0x1544_8493_2d2e_725a_5bbc_a17a_3aba_173e,
+    0x1103_9d42_8a8b_8eae_afca_1ac8_2efb_45cb,
+    0x1b38_fb9d_aa78_e44a_b2dc_f7a6_b192_0945,
+    0x15c7_2fb1_552d_836e_f57d_92eb_c141_a104,
+    0x116c_2627_7757_9c58_c464_7589_6767_b403,
+    0x1be0_3d0b_f225_c6f4_6d6d_88db_d8a5_ecd2,
+    0x164c_fda3_281e_38c3_8abe_0716_46eb_23db,
+    0x11d7_314f_534b_609c_6efe_6c11_d255_b649,
+    0x1c8b_8218_8545_6760_b197_134f_b6ef_8a0e,
+    0x16d6_01ad_376a_b91a_27ac_0f72_f8bf_a1a5,
+    0x1244_ce24_2c55_60e1_b956_72c2_6099_4e1e,
+    0x1d3a_e36d_13bb_ce35_f557_1e03_cdc2_1695,
+    0x1762_4f8a_762f_d82b_2aac_1803_0b01_abab,
+    0x12b5_0c6e_c4f3_1355_bbbc_e002_6f34_8956,
+    0x1dee_7a4a_d4b8_1eef_92c7_ccd0_b1ed_a889,
+    0x17f1_fb6f_1093_4bf2_dbd3_0a40_8e57_ba07,
+    0x1327_fc58_da0f_6ff5_7ca8_d500_71df_c806,
+    0x1ea6_608e_29b2_4cbb_faa7_bb33_e966_0cd6,
+    0x1885_1a0b_548e_a3c9_9552_fc29_8784_d711,
+    0x139d_ae6f_76d8_8307_aaa8_c9ba_d2d0_ac0e,
+    0x1f62_b0b2_57c0_d1a5_ddda_dc5e_1e1a_ace3,
+    0x191b_c08e_ac9a_4151_7e48_b04b_4b48_8a4f,
+    0x1416_33a5_56e1_cdda_cb6d_59d5_d5d3_a1d9,
+    0x1011_c2ea_abe7_d7e2_3c57_7b11_77dc_817b,
+    0x19b6_04aa_aca6_2636_c6f2_5e82_5960_cf2a,
+    0x1491_9d55_56eb_51c5_6bf5_1868_4780_a5bb,
+    0x1074_7ddd_df22_a7d1_232a_79ed_0600_8496,
+    0x1a53_fc96_31d1_0c81_d1dd_8fe1_a334_0756,
+    0x150f_fd44_f4a7_3d34_a7e4_731a_e8f6_6c45,
+    0x10d9_976a_5d52_975d_531d_28e2_53f8_569e,
+    0x1af5_bf10_9550_f22e_eb61_db03_b98d_5762,
+    0x1591_65a6_ddda_5b58_bc4e_48cf_c7a4_45e8,
+    0x1141_1e1f_17e1_e2ad_6371_d3d9_6c83_6b20,
+    0x1b9b_6364_f303_0448_9f1c_8628_ad9f_11cd,
+    0x1615_e91d_8f35_9d06_e5b0_6b53_be18_db0b,
+    0x11ab_20e4_7291_4a6b_eaf3_890f_cb47_15a2,
+    0x1c45_016d_841b_aa46_44b8_db4c_7871_bc37,
+    0x169d_9abe_0349_5505_03c7_15d6_c6c1_635f,
+    0x1217_aefe_6907_7737_3638_de45_6bcd_e919,
+    0x1cf2_b197_0e72_5858_56c1_63a2_4616_41c1,
+    0x1728_8e12_71f5_1379_df01_1c81_d1ab_67ce,
+    0x1286_d80e_c190_dc61_7f34_16ce_4155_eca5,
+    0x1da4_8ce4_68e7_c702_6520_247d_3556_476e,
+    0x17b6_d71d_20b9_6c01_ea80_1d30_f778_3925,
+    0x12f8_ac17_4d61_2334_bb99_b0f3_f92c_fa84,
+    0x1e5a_acf2_1568_3854_5f5c_4e53_2847_f739,
+    0x1848_8a5b_4453_6043_7f7d_0b75_b9d3_2c2e,
+    0x136d_3b7c_36a9_19cf_9930_d5f7_c7dc_2358,
+    0x1f15_2bf9_f10e_8fb2_8eb4_898c_72f9_d226,
+    0x18dd_bcc7_f40b_a628_722a_07a3_8f2e_41b8,
+    0x13e4_9706_5cd6_1e86_c1bb_394f_a5be_9afa,
+    0x1fd4_24d6_faf0_30d7_9c5e_c219_0930_f7f6,
+    0x1976_83df_2f26_8d79_49e5_6814_075a_5ff8,
+    0x145e_cfe5_bf52_0ac7_6e51_2010_05e1_e660,
+    0x104b_d984_990e_6f05_f1da_800c_d181_851a,
+    0x1a12_f5a0_f4e3_e4d6_4fc4_0014_8268_d4f5,
+    0x14db_f7b3_f71c_b711_d969_99aa_01ed_772b,
+    0x10af_f95c_c5b0_9274_adee_1488_018a_c5bc,
+    0x1ab3_2894_6f80_ea54_497c_eda6_68de_092c,
+    0x155c_2076_bf9a_5510_3aca_57b8_53e4_d424,
+    0x1116_805e_ffae_aa73_623b_7960_431d_7683,
+    0x1b57_33cb_32b1_10b8_9d2b_f566_d1c8_bd9e,
+    0x15df_5ca2_8ef4_0d60_7dbc_c452_416d_647f,
+    0x117f_7d4e_d8c3_3de6_cafd_69db_678a_b6cc,
+    0x1bff_2ee4_8e05_2fd7_ab2f_0fc5_7277_8adf,
+    0x1665_bf1d_3e6a_8cac_88f2_7304_5b92_d580,
+    0x11ea_ff4a_9855_3d56_d3f5_28d0_4942_4466,
+    0x1cab_3210_f3bb_9557_b988_414d_4203_a0a3,
+    0x16ef_5b40_c2fc_7779_6139_cdd7_6802_e6e9,
+    0x1259_15cd_68c9_f92d_e761_7179_2002_5254,
+    0x1d5b_5615_7476_5b7c_a568_b58e_999d_5086,
+    0x177c_44dd_f6c5_15fd_5120_913e_e14a_a6d2,
+    0x12c9_d0b1_9237_44ca_a74d_40ff_1aa2_1f0e,
+    0x1e0f_b44f_5058_6e11_0bae_ce64_f769_cb4a,
+    0x180c_903f_7379_f1a7_3c8b_d850_c5ee_3c3b,
+    0x133d_4032_c2c7_f485_ca09_79da_37f1_c9c9,
+    0x1ec8_66b7_9e0c_ba6f_a9a8_c2f6_bfe9_42db,
+    0x18a0_522c_7e70_9526_2153_cf2b_ccba_9be3,
+    0x13b3_74f0_6526_ddb8_1aa9_7289_7095_4982,
+    0x1f85_87e7_083e_2f8c_f775_840f_1a88_759d,
+    0x1937_9fec_0698_260a_5f91_3672_7ba0_5e17,
+    0x142c_7ff0_0546_84d5_1940_f85b_9619_e4df,
+    0x1023_998c_d105_3710_e100_c6af_ab47_ea4c,
+    0x19d2_8f47_b4d5_24e7_ce67_a44c_453f_dd47,
+    0x14a8_729f_c3dd_b71f_d852_e9d6_9dcc_b106,
+    0x1086_c219_697e_2c19_79db_ee45_4b0a_2738,
+    0x1a71_368f_0f30_468f_295f_e3a2_11a9_d859,
+    0x1527_5ed8_d8f3_6ba5_bab3_1c81_a7bb_137a,
+    0x10ec_4be0_ad8f_8951_6228_e39a_ec95_a92f,
+    0x1b13_ac9a_af4c_0ee8_9d0e_38f7_e0ef_7517,
+    0x15a9_56e2_25d6_7253_b0d8_2d93_1a59_2a79,
+    0x1154_4581_b7de_c1dc_8d79_be0f_4847_552e,
+    0x1bba_08cf_8c97_9c94_158f_967e_da0b_bb7c,
+    0x162e_6d72_d6df_b076_77a6_11ff_14d6_2f97,
+    0x11be_bdf5_78b2_f391_f951_a7ff_43de_8c79,
+    0x1c64_6322_5ab7_ec1c_c21c_3ffe_d2fd_ad8e,
+    0x16b6_b5b5_155f_f017_01b0_3332_4264_8ad8,
+    0x122b_c490_dde6_59ac_0159_c28e_9b83_a246,
+    0x1d12_d41a_fca3_c2ac_cef6_0417_5f39_03a3,
+    0x1742_4348_ca1c_9bbd_725e_69ac_4c2d_9c83,
+    0x129b_6907_0816_e2fd_f518_5489_d68a_e39c,
+    0x1dc5_74d8_0cf1_6b2f_ee8d_540f_bdab_05c6,
+    0x17d1_2a46_70c1_228c_bed7_7672_fe22_6b05,
+    0x130d_bb6b_8d67_4ed6_ff12_c528_cb4e_bc04,
+    0x1e7c_5f12_7bd8_7e24_cb51_3b74_787d_f9a0,
+    0x1863_7f41_fcad_31b7_090d_c929_f9fe_614d,
+    0x1382_cc34_ca24_27c5_a0d7_d421_94cb_810a,
+    0x1f37_ad21_436d_0c6f_67bf_b9cf_5478_ce77,
+    0x18f9_574d_cf8a_7059_1fcc_94a5_dd2d_71f9,
+    0x13fa_ac3e_3fa1_f37a_7fd6_dd51_7dbd_f4c7,
+    0x1ff7_79fd_329c_b8c3_ffbe_2ee8_c92f_ee0b,
+    0x1992_c7fd_c216_fa36_6631_bf20_a0f3_24d6,
+    0x1475_6ccb_01ab_fb5e_b827_cc1a_1a5c_1d78,
+    0x105d_f0a2_67bc_c918_9353_09ae_7b7c_e460,
+    0x1a2f_e76a_3f94_74f4_1eeb_42b0_c594_a099,
+    0x14f3_1f88_32dd_2a5c_e589_0227_0476_e6e1,
+    0x10c2_7fa0_28b0_eeb0_b7a0_ce85_9d2b_ebe7,
+    0x1ad0_cc33_744e_4ab4_5901_4a6f_61df_dfd8,
+    0x1573_d68f_903e_a229_e0cd_d525_e7e6_4cad,
+    0x1129_7872_d9cb_b4ee_4d71_7751_8651_d6f1,
+    0x1b75_8d84_8fac_54b0_7be8_bee8_d6e9_57e8,
+    0x15f7_a46a_0c89_dd59_fcba_3253_df21_1320,
+    0x1192_e9ee_706e_4aae_63c8_2843_18e7_4280,
+    0x1c1e_4317_1a4a_1117_060d_0d38_27d8_6a66,
+    0x167e_9c12_7b6e_7412_6b3d_a42c_ecad_21eb,
+    0x11fe_e341_fc58_5cdb_88fe_1cf0_bd57_4e56,
+    0x1ccb_0536_608d_615f_4196_94b4_6225_4a23,
+    0x1708_d0f8_4d3d_e77f_67ab_aa29_e81d_d4e9,
+    0x126d_73f9_d764_b932_b956_21bb_2017_dd87,
+    0x1d7b_ecc2_f23a_c1ea_c223_692b_668c_95a5,
+    0x1796_5702_5b62_34bb_ce82_ba89_1ed6_de1d,
+    0x12de_ac01_e2b4_f6fc_a535_6207_4bdf_1818,
+    0x1e31_1336_3787_f194_3b88_9cd8_7964_f359,
+    0x1827_4291_c606_5adc_fc6d_4a46_c783_f5e1,
+    0x1352_9ba7_d19e_af17_3057_6e9f_0603_2b1a,
+    0x1eea_92a6_1c31_1825_1a25_7dcb_3cd1_de90,
+    0x18bb_a884_e35a_79b7_481d_fe3c_30a7_e540,
+    0x13c9_539d_82ae_c7c5_d34b_31c9_c086_5100,
+    0x1fa8_85c8_d117_a609_5211_e942_cda3_b4cd,
+    0x1953_9e3a_40df_b807_74db_2102_3e1c_90a4,
+    0x1442_e4fb_6719_6005_f715_b401_cb4a_0d50,
+    0x1035_83fc_527a_b337_f8de_299b_0908_0aa7,
+    0x19ef_3993_b72a_b859_8e30_4291_a80c_ddd7,
+    0x14bf_6142_f8ee_f9e1_3e8d_020e_200a_4b13,
+    0x1099_1a9b_fa58_c7e7_653d_9b3e_8008_3c0f,
+    0x1a8e_90f9_908e_0ca5_6ec8_f864_000d_2ce4,
+    0x153e_da61_4071_a3b7_8bd3_f9e9_99a4_23ea,
+    0x10ff_151a_99f4_82f9_3ca9_94ba_e150_1cbb,
+    0x1b31_bb5d_c320_d18e_c775_bac4_9bb3_612b,
+    0x15c1_62b1_68e7_0e0b_d2c4_956a_1629_1a89,
+    0x1167_8227_871f_3e6f_dbd0_7788_11ba_7ba1,
+    0x1bd8_d03f_3e98_63e6_2c80_bf40_1c5d_929b,
+    0x1647_0cff_6546_b651_bd33_cc33_49e4_7549,
+    0x11d2_70cc_5105_5ea7_ca8f_d68f_6e50_5dd4,
+    0x1c83_e7ad_4e6e_fdd9_4419_574b_e3b3_c953,
+    0x16cf_ec8a_a525_97e1_0347_7909_82f6_3aa9,
+    0x123f_f06e_ea84_7980_cf6c_60d4_68c4_fbba,
+    0x1d33_1a4b_10d3_f59a_e57a_3487_0e07_f92a,
+    0x175c_1508_da43_2ae2_512e_906c_0b39_9422,
+    0x12b0_10d3_e1cf_5581_da8b_a6bc_d5c7_a9b5,
+    0x1de6_8153_02e5_559c_90df_712e_22d9_0f87,
+    0x17eb_9aa8_cf1d_de16_da4c_5a8b_4f14_0c6c,
+    0x1322_e220_a5b1_7e78_aea3_7ba2_a5a9_a38a,
+    0x1e9e_369a_a2b5_9727_7dd2_5f6a_a2a9_05a9,
+    0x187e_9215_4ef7_ac1f_97db_7f88_8220_d154,
+    0x1398_74dd_d8c6_234c_797c_6606_ce80_a777,
+    0x1f5a_5496_27a3_6bad_8f2d_700a_e401_0bf1,
+    0x1915_1078_1fb5_efbe_0c24_59a2_5000_d65a,
+    0x1410_d9f9_b2f7_f2fe_701d_1481_d99a_4515,
+    0x100d_7b2e_28c6_5bfe_c017_439b_147b_6a77,
+    0x19af_2b7d_0e0a_2cca_ccf2_05c4_ed92_43f2,
+    0x148c_22ca_71a1_bd6f_0a5b_37d0_be0e_9cc2,
+    0x1070_1bd5_27b4_978c_0848_f973_cb3e_e3ce,
+    0x1a4c_f955_0c54_25ac_da0e_5bec_7864_9fb0,
+    0x150a_6110_d6a9_b7bd_7b3e_aff0_6050_7fc0,
+    0x10d5_1a73_deee_2c97_95cb_bff3_8040_6633,
+    0x1aee_90b9_64b0_4758_efac_6652_66cd_7052,
+    0x158b_a6fa_b6f3_6c47_2623_850e_b8a4_59db,
+    0x113c_8595_5f29_236c_1e82_d0d8_93b6_ae49,
+    0x1b94_08ee_fea8_38ac_fd9e_1af4_1f8a_b075,
+    0x1610_0725_9886_93bd_97b1_af29_b2d5_59f7,
+    0x11a6_6c1e_139e_dc97_ac8e_25ba_f577_7b2c,
+    0x1c3d_79c9_b8fe_2dbf_7a7d_092b_2258_c513,
+    0x1697_94a1_60cb_57cc_61fd_a0ef_4ead_6a76,
+    0x1212_dd4d_e709_1309_e7fe_1a59_0bbd_eec5,
+    0x1cea_fbaf_d80e_84dc_a663_5d5b_45fc_b13a,
+    0x1722_62f3_133e_d0b0_851c_4aaf_6b30_8dc8,
+    0x1281_e8c2_75cb_da26_d0e3_6ef2_bc26_d7d4,
+    0x1d9c_a79d_8946_29d7_b49f_17ea_c6a4_8c86,
+    0x17b0_8617_a104_ee46_2a18_dfef_0550_706b,
+    0x12f3_9e79_4d9d_8b6b_54e0_b325_9dd9_f389,
+    0x1e52_9728_7c2f_4578_87cd_eb6f_62f6_5274,
+    0x1842_1286_c9bf_6ac6_d30b_22bf_825e_a85d,
+    0x1368_0ed2_3aff_889f_0f3c_1bcc_684b_b9e4,
+    0x1f0c_e483_9198_da98_1860_2c7a_4079_296d,
+    0x18d7_1d36_0e13_e213_46b3_56c8_3394_2124,
+    0x13df_4a91_a4dc_b4dc_388f_78a0_2943_4db6,
+    0x1fcb_aa82_a161_2160_5a7f_2766_a86b_af8a,
+    0x196f_bb9b_b44d_b44d_1532_85eb_b9ef_bfa2,
+    0x1459_62e2_f6a4_903d_aa8e_d189_618c_994e,
+    0x1047_824f_2bb6_d9ca_eed8_a7a1_1ad6_e10c,
+    0x1a0c_03b1_df8a_f611_7e27_729b_5e24_9b45,
+    0x14d6_695b_193b_f80d_fe85_f549_181d_4904,
+    0x10ab_877c_142f_f9a4_cb9e_5dd4_134a_a0d0,
+    0x1aac_0bf9_b9e6_5c3a_df63_c953_5211_014d,
+    0x1556_6ffa_fb1e_b02f_191c_a10f_74da_6771,
+    0x1111_f32f_2f4b_c025_adb0_80d9_2a48_52c1,
+    0x1b4f_eb7e_b212_cd09_15e7_348e_aa0d_5134,
+    0x15d9_8932_280f_0a6d_ab1f_5d3e_ee71_0dc4,
+    0x117a_d428_200c_0857_bc19_1765_8b8d_a49d,
+    0x1bf7_b9d9_cce0_0d59_2cf4_f23c_127c_3a94,
+    0x165f_c7e1_70b3_3de0_f0c3_f4fc_db96_9543,
+    0x11e6_3981_26f5_cb1a_5a36_5d97_1612_1103,
+    0x1ca3_8f35_0b22_de90_9056_fc24_f01c_e804,
+    0x16e9_3f5d_a282_4ba6_d9df_301d_8ce3_ecd0,
+    0x1254_32b1_4ece_a2eb_e17f_59b1_3d83_23da,
+    0x1d53_844e_e47d_d179_68cb_c2b5_2f38_395c,
+    0x1776_0372_5064_a794_53d6_355d_bf60_2de3,
+    0x12c4_cf8e_a6b6_ec76_a978_2ab1_65e6_8b1c,
+    0x1e07_b27d_d78b_13f1_0f26_aab5_6fd7_44fa,
+    0x1806_2864_ac6f_4327_3f52_222a_bfdf_6a62,
+    0x1338_2050_89f2_9c1f_65db_4e88_997f_884e,
+    0x1ec0_33b4_0fea_9365_6fc5_4a74_28cc_0d4a,
+    0x1899_c2f6_7322_0f84_596a_a1f6_8709_a43b,
+    0x13ae_3591_f5b4_d936_adee_e7f8_6c07_b696,
+    0x1f7d_2283_22ba_f524_497e_3ff3_e00c_5756,
+    0x1930_e868_e895_90e9_d464_fff6_4cd6_ac45,
+    0x1427_2053_ed44_73ee_4383_fff8_3d78_89d1,
+    0x101f_4d0f_f103_8ff1_cf9c_ccc6_9793_a174,
+    0x19cb_ae7f_e805_b31c_7f61_47a4_25b9_0252,
+    0x14a2_f1ff_ecd1_5c16_cc4d_d2e9_b7c7_350f,
+    0x1082_5b33_23da_b012_3d0b_0f21_5fd2_90d9,
+    0x1a6a_2b85_062a_b350_61ab_4b68_9950_e7c1,
+    0x1521_bc6a_6b55_5c40_4e22_a2ba_1440_b967,
+    0x10e7_c9ee_bc44_49cd_0b4e_e894_dd00_9453,
+    0x1b0c_764a_c6d3_a948_1217_da87_c800_ed51,
+    0x15a3_91d5_6bdc_876c_db46_486c_a000_bdda,
+    0x114f_a7dd_efe3_9f8a_4905_06bd_4ccd_64af,
+    0x1bb2_a62f_e638_ff43_a808_0ac8_7ae2_3ab1,
+    0x1628_84f3_1e93_ff69_5339_a239_fbe8_2ef4,
+    0x11ba_03f5_b20f_ff87_75c7_b4fb_2fec_f25d,
+    0x1c5c_d322_b67f_ff3f_22d9_2191_e647_ea2e,
+    0x16b0_a8e8_91ff_ff65_b57a_8141_8506_54f2,
+    0x1226_ed86_db33_32b7_c462_0101_3738_43f5,
+    0x1d0b_15a4_91eb_8459_3a36_6801_f1f3_9fee,
+    0x173c_1150_74bc_69e0_fb5e_b99b_27f6_198b,
+    0x1296_7440_5d63_87e7_2f7e_fae2_865e_7ad6,
+    0x1dbd_86cd_6238_d971_e597_f7d0_d6fd_9156,
+    0x17ca_d23d_e82d_7ac1_8479_930d_78ca_daab,
+    0x1308_a831_868a_c89a_d061_4271_2d6f_1556,
+    0x1e74_404f_3daa_da91_4d68_6a4e_af18_2222,
+    0x185d_003f_6488_aeda_a453_883e_f279_b4e8,
+    0x137d_99cc_506d_58ae_e9dc_6cff_2861_5d87,
+    0x1f2f_5c7a_1a48_8de4_a960_ae65_0d68_95a4,
+    0x18f2_b061_aea0_7183_bab3_beb7_3ded_4483,
+    0x13f5_59e7_bee6_c136_2ef6_322c_318a_9d36,
+    0x1fee_f63f_97d7_9b89_e4bd_1d13_8277_61f0,
+    0x198b_f832_dfdf_afa1_83ca_7da9_352c_4e5a,
+    0x146f_f9c2_4cb2_f2e7_9ca1_fe20_f756_a515,
+    0x1059_949b_708f_28b9_4a1b_31b3_f912_1daa,
+    0x1a28_edc5_80e5_0df5_435e_b5ec_c1b6_95dd,
+    0x14ed_8b04_671d_a4c4_35e5_5e57_015e_de4a,
+    0x10be_08d0_527e_1d69_c4b7_7eac_0118_b1d5,
+    0x1ac9_a7b3_b730_2f0f_a125_9779_9b5a_b622,
+    0x156e_1fc2_f8f3_58d9_4db7_ac61_4915_5e81,
+    0x1124_e635_93f5_e0ad_d7c6_2381_0744_4b9b,
+    0x1b6e_3d22_8656_3449_593d_059b_3ed3_ac2b,
+    0x15f1_ca82_0511_c36d_e0fd_9e15_cbdc_89bc,
+    0x118e_3b9b_3741_6924_b3fe_1811_6fe3_a163,
+    0x1c16_c5c5_2535_7507_8663_59b5_7fd2_9bd1,
+    0x1678_9e37_50f7_90d2_d1e9_1491_330e_e30e,
+    0x11fa_182c_40c6_0d75_74ba_76da_8f3f_1c0b,
+    0x1cc3_59e0_67a3_48bb_edf7_2490_e531_c678,
+    0x1702_ae4d_1fb5_d3c9_8b2c_1d40_b75b_052d,
+    0x1268_8b70_e62b_0fd4_6f56_7dcd_5f7c_0424,
+    0x1d74_124e_3d11_b2ed_7ef0_c948_98c6_6d06,
+    0x1790_0ea4_fda7_c257_98c0_a106_e09e_bd9f,
+    0x12d9_a550_caec_9b79_4700_80d2_4d4b_cae6,
+    0x1e29_0881_44ad_c58e_d800_ce1d_4879_44a2,
+    0x1820_d39a_9d57_d13f_1333_d817_6d2d_d082,
+    0x134d_7615_4aac_a765_a8f6_4679_2424_a6ce,
+    0x1ee2_5688_777a_a56f_74bd_3d8e_a03a_a47d,
+    0x18b5_1206_c5fb_b78c_5d64_313e_e695_5064,
+    0x13c4_0e6b_d196_2c70_4ab6_8dcb_ebaa_a6b7,
+    0x1fa0_1712_e8f0_471a_1124_1613_12aa_a457,
+    0x194c_df42_53f3_6c14_da83_44dc_0eee_e9df,
+    0x143d_7f68_4329_2343_e202_9d7c_d8bf_2180,
+    0x1031_32b9_cf54_1c36_4e68_7dfd_7a32_8133,
+    0x19e8_5129_4bb9_c6bd_4a40_c995_9050_ceb8,
+    0x14b9_da87_6fc7_d231_0833_d477_a6a7_0bc6,
+    0x1094_aed2_bfd3_0e8d_a029_76c6_1eec_096b,
+    0x1a87_7e1d_ffb8_1749_0042_57a3_64ac_dbdf,
+    0x1539_31b1_9960_12a0_cd01_dfb5_ea23_e319,
+    0x10fa_8e27_ade6_754d_70ce_4c91_881c_b5ae,
+    0x1b2a_7d0c_4970_bbaf_1ae3_adb5_a694_55e2,
+    0x15bb_973d_078d_62f2_7be9_57c4_8543_77e8,
+    0x1162_df64_060a_b58e_c987_796a_0435_f987,
+    0x1bd1_656c_d677_88e4_75a5_8f10_06bc_c271,
+    0x1641_1df0_ab92_d3e9_f7b7_a5a6_6bca_3527,
+    0x11cd_b18d_560f_0fee_5fc6_1e1e_bca1_c41f,
+    0x1c7c_4f48_89b1_b316_ffa3_6364_6102_d365,
+    0x16c9_d906_d48e_28df_32e9_1c50_4d9b_dc51,
+    0x123b_1405_76d8_20b2_8f20_e373_7149_7d0e,
+    0x1d2b_533b_f159_cdea_7e9b_0585_820f_2e7c,
+    0x1755_dc2f_f447_d7ee_cbaf_379e_01a5_beca,
+    0x12ab_168c_c36c_acbf_0958_f94b_3484_98a1,
+];
+
+pub const DOUBLE_POW5_SPLIT: [u128; 326] = [
+    0x1000_0000_0000_0000_0000_0000_0000_0000,
+    0x1400_0000_0000_0000_0000_0000_0000_0000,
+    0x1900_0000_0000_0000_0000_0000_0000_0000,
+    0x1f40_0000_0000_0000_0000_0000_0000_0000,
+    0x1388_0000_0000_0000_0000_0000_0000_0000,
+    0x186a_0000_0000_0000_0000_0000_0000_0000,
+    0x1e84_8000_0000_0000_0000_0000_0000_0000,
+    0x1312_d000_0000_0000_0000_0000_0000_0000,
+    0x17d7_8400_0000_0000_0000_0000_0000_0000,
+    0x1dcd_6500_0000_0000_0000_0000_0000_0000,
+    0x12a0_5f20_0000_0000_0000_0000_0000_0000,
+    0x1748_76e8_0000_0000_0000_0000_0000_0000,
+    0x1d1a_94a2_0000_0000_0000_0000_0000_0000,
+    0x1230_9ce5_4000_0000_0000_0000_0000_0000,
+    0x16bc_c41e_9000_0000_0000_0000_0000_0000,
+    0x1c6b_f526_3400_0000_0000_0000_0000_0000,
+    0x11c3_7937_e080_0000_0000_0000_0000_0000,
+    0x1634_5785_d8a0_0000_0000_0000_0000_0000,
+    0x1bc1_6d67_4ec8_0000_0000_0000_0000_0000,
+    0x1158_e460_913d_0000_0000_0000_0000_0000,
+    0x15af_1d78_b58c_4000_0000_0000_0000_0000,
+    0x1b1a_e4d6_e2ef_5000_0000_0000_0000_0000,
+    0x10f0_cf06_4dd5_9200_0000_0000_0000_0000,
+    0x152d_02c7_e14a_f680_0000_0000_0000_0000,
+    0x1a78_4379_d99d_b420_0000_0000_0000_0000,
+    0x108b_2a2c_2802_9094_0000_0000_0000_0000,
+    0x14ad_f4b7_3203_34b9_0000_0000_0000_0000,
+    0x19d9_71e4_fe84_01e7_4000_0000_0000_0000,
+    0x1027_e72f_1f12_8130_8800_0000_0000_0000,
+    0x1431_e0fa_e6d7_217c_aa00_0000_0000_0000,
+    0x193e_5939_a08c_e9db_d480_0000_0000_0000,
+    0x1f8d_ef88_08b0_2452_c9a0_0000_0000_0000,
+    0x13b8_b5b5_056e_16b3_be04_0000_0000_0000,
+    0x18a6_e322_46c9_9c60_ad85_0000_0000_0000,
+    0x1ed0_9bea_d87c_0378_d8e6_4000_0000_0000,
+    0x1342_6172_c74d_822b_878f_e800_0000_0000,
+    0x1812_f9cf_7920_e2b6_6973_e200_0000_0000,
+    0x1e17_b843_5769_1b64_03d0_da80_0000_0000,
+    0x12ce_d32a_16a1_b11e_8262_8890_0000_0000,
+    0x1782_87f4_9c4a_1d66_22fb_2ab4_0000_0000,
+    0x1d63_29f1_c35c_a4bf_abb9_f561_0000_0000,
+    0x125d_fa37_1a19_e6f7_cb54_395c_a000_0000,
+    0x16f5_78c4_e0a0_60b5_be29_47b3_c800_0000,
+    0x1cb2_d6f6_18c8_78e3_2db3_99a0_ba00_0000,
+    0x11ef_c659_cf7d_4b8d_fc90_4004_7440_0000,
+    0x166b_b7f0_435c_9e71_7bb4_5005_9150_0000,
+    0x1c06_a5ec_5433_c60d_daa1_6406_f5a4_0000,
+    0x1184_27b3_b4a0_5bc8_a8a4_de84_5986_8000,
+    0x15e5_31a0_a1c8_72ba_d2ce_1625_6fe8_2000,
+    0x1b5e_7e08_ca3a_8f69_8781_9bae_cbe2_2800,
+    0x111b_0ec5_7e64_99a1_f4b1_014d_3f6d_5900,
+    0x1561_d276_ddfd_c00a_71dd_41a0_8f48_af40,
+    0x1aba_4714_957d_300d_0e54_9208_b31a_db10,
+    0x10b4_6c6c_dd6e_3e08_28f4_db45_6ff0_c8ea,
+    0x14e1_8788_14c9_cd8a_3332_1216_cbec_fb24,
+    0x1a19_e96a_19fc_40ec_bffe_969c_7ee8_39ed,
+    0x1050_31e2_503d_a893_f7ff_1e21_cf51_2434,
+    0x1464_3e5a_e44d_12b8_f5fe_e5aa_4325_6d41,
+    0x197d_4df1_9d60_5767_337e_9f14_d3ee_c892,
+    0x1fdc_a16e_04b8_6d41_005e_46da_08ea_7ab6,
+    0x13e9_e4e4_c2f3_4448_a03a_ec48_4592_8cb2,
+    0x18e4_5e1d_f3b0_155a_c849_a75a_56f7_2fde,
+    0x1f1d_75a5_709c_1ab1_7a5c_1130_ecb4_fbd6,
+    0x1372_6987_6661_90ae_ec79_8abe_93f1_1d65,
+    0x184f_03e9_3ff9_f4da_a797_ed6e_38ed_64bf,
+    0x1e62_c4e3_8ff8_7211_517d_e8c9_c728_bdef,
+    0x12fd_bb0e_39fb_474a_d2ee_b17e_1c79_76b5,
+    0x17bd_29d1_c87a_191d_87aa_5ddd_a397_d462,
+    0x1dac_7446_3a98_9f64_e994_f555_0c7d_c97b,
+    0x128b_c8ab_e49f_639f_11fd_1955_27ce_9ded,
+    0x172e_bad6_ddc7_3c86_d67c_5faa_71c2_4568,
+    0x1cfa_698c_9539_0ba8_8c1b_7795_0e32_d6c2,
+    0x121c_81f7_dd43_a749_5791_2abd_28df_c639,
+    0x16a3_a275_d494_911b_ad75_756c_7317_b7c8,
+    0x1c4c_8b13_49b9_b562_98d2_d2c7_8fdd_a5ba,
+    0x11af_d6ec_0e14_115d_9f83_c3bc_b9ea_8794,
+    0x161b_cca7_1199_15b5_0764_b4ab_e865_2979,
+    0x1ba2_bfd0_d5ff_5b22_493d_e1d6_e27e_73d7,
+    0x1145_b7e2_85bf_98f5_6dc6_ad26_4d8f_0866,
+    0x1597_25db_272f_7f32_c938_586f_e0f2_ca80,
+    0x1afc_ef51_f0fb_5eff_7b86_6e8b_d92f_7d20,
+    0x10de_1593_369d_1b5f_ad34_0517_67bd_ae34,
+    0x1515_9af8_0444_6237_9881_065d_41ad_19c1,
+    0x1a5b_01b6_0555_7ac5_7ea1_47f4_9218_6032,
+    0x1078_e111_c355_6cbb_6f24_ccf8_db4f_3c1f,
+    0x1497_1956_342a_c7ea_4aee_0037_1223_0b27,
+    0x19bc_dfab_c135_79e4_dda9_8044_d6ab_cdf0,
+    0x1016_0bcb_58c1_6c2f_0a89_f02b_062b_60b6,
+    0x141b_8ebe_2ef1_c73a_cd2c_6c35_c7b6_38e4,
+    0x1922_726d_baae_3909_8077_8743_39a3_c71d,
+    0x1f6b_0f09_2959_c74b_e095_6914_080c_b8e4,
+    0x13a2_e965_b9d8_1c8f_6c5d_61ac_8507_f38e,
+    0x188b_a3bf_284e_23b3_4774_ba17_a649_f072,
+    0x1eae_8cae_f261_aca0_1951_e89d_8fdc_6c8f,
+    0x132d_17ed_577d_0be4_0fd3_3162_79e9_c3d9,
+    0x17f8_5de8_ad5c_4edd_13c7_fdbb_1864_34cf,
+    0x1df6_7562_d8b3_6294_58b9_fd29_de7d_4203,
+    0x12ba_095d_c770_1d9c_b774_3e3a_2b0e_4942,
+    0x1768_8bb5_394c_2503_e551_4dc8_b5d1_db92,
+    0x1d42_aea2_879f_2e44_dea5_a13a_e346_5277,
+    0x1249_ad25_94c3_7ceb_0b27_84c4_ce0b_f38a,
+    0x16dc_186e_f9f4_5c25_cdf1_65f6_018e_f06d,
+    0x1c93_1e8a_b871_732f_416d_bf73_81f2_ac88,
+    0x11db_f316_b346_e7fd_88e4_97a8_3137_abd5,
+    0x1652_efdc_6018_a1fc_eb1d_bd92_3d85_96ca,
+    0x1be7_abd3_781e_ca7c_25e5_2cf6_cce6_fc7d,
+    0x1170_cb64_2b13_3e8d_97af_3c1a_4010_5dce,
+    0x15cc_fe3d_35d8_0e30_fd9b_0b20_d014_7542,
+    0x1b40_3dcc_834e_11bd_3d01_cde9_0419_9292,
+    0x1108_269f_d210_cb16_4621_20b1_a28f_fb9b,
+    0x154a_3047_c694_fddb_d7a9_68de_0b33_fa82,
+    0x1a9c_bc59_b83a_3d52_cd93_c315_8e00_f923,
+    0x10a1_f5b8_1324_6653_c07c_59ed_78c0_9bb6,
+    0x14ca_7326_17ed_7fe8_b09b_7068_d6f0_c2a3,
+    0x19fd_0fef_9de8_dfe2_dcc2_4c83_0cac_f34c,
+    0x103e_29f5_c2b1_8bed_c9f9_6fd1_e7ec_180f,
+    0x144d_b473_335d_eee9_3c77_cbc6_61e7_1e13,
+    0x1961_2190_0035_6aa3_8b95_beb7_fa60_e598,
+    0x1fb9_69f4_0042_c54c_6e7b_2e65_f8f9_1efe,
+    0x13d3_e238_8029_bb4f_c50c_fcff_bb9b_b35f,
+    0x18c8_dac6_a034_2a23_b650_3c3f_aa82_a037,
+    0x1efb_1178_4841_34ac_a3e4_4b4f_9523_4844,
+    0x135c_eaeb_2d28_c0eb_e66e_af11_bd36_0d2b,
+    0x1834_25a5_f872_f126_e00a_5ad6_2c83_9075,
+    0x1e41_2f0f_768f_ad70_980c_f18b_b7a4_7493,
+    0x12e8_bd69_aa19_cc66_5f08_16f7_52c6_c8dc,
+    0x17a2_ecc4_14a0_3f7f_f6ca_1cb5_2778_7b13,
+    0x1d8b_a7f5_19c8_4f5f_f47c_a3e2_7156_99d7,
+    0x1277_48f9_301d_319b_f8cd_e66d_86d6_2026,
+    0x1715_1b37_7c24_7e02_f701_6008_e88b_a830,
+    0x1cda_6205_5b2d_9d83_b4c1_b80b_22ae_923c,
+    0x1208_7d43_58fc_8272_50f9_1306_f5ad_1b65,
+    0x168a_9c94_2f3b_a30e_e537_57c8_b318_623f,
+    0x1c2d_43b9_3b0a_8bd2_9e85_2dba_dfde_7acf,
+    0x119c_4a53_c4e6_9763_a313_3c94_cbeb_0cc1,
+    0x1603_5ce8_b620_3d3c_8bd8_0bb9_fee5_cff1,
+    0x1b84_3422_e3a8_4c8b_aece_0ea8_7e9f_43ee,
+    0x1132_a095_ce49_2fd7_4d40_c929_4f23_8a75,
+    0x157f_48bb_41db_7bcd_2090_fb73_a2ec_6d12,
+    0x1adf_1aea_1252_5ac0_68b5_3a50_8ba7_8856,
+    0x10cb_70d2_4b73_78b8_4171_4472_5748_b536,
+    0x14fe_4d06_de50_56e6_51cd_958e_ed1a_e283,
+    0x1a3d_e048_95e4_6c9f_e640_faf2_a861_9b24,
+    0x1066_ac2d_5dae_c3e3_efe8_9cd7_a93d_00f7,
+    0x1480_5738_b51a_74dc_ebe2_c40d_938c_4134,
+    0x19a0_6d06_e261_1214_26db_7510_f86f_5181,
+    0x1004_4424_4d7c_ab4c_9849_292a_9b45_92f1,
+    0x1405_552d_60db_d61f_be5b_7375_4216_f7ad,
+    0x1906_aa78_b912_cba7_adf2_5052_929c_b598,
+    0x1f48_5516_e757_7e91_996e_e467_3743_e2ff,
+    0x138d_352e_5096_af1a_ffe5_4ec0_828a_6ddf,
+    0x1870_8279_e4bc_5ae1_bfde_a270_a32d_0957,
+    0x1e8c_a318_5deb_719a_2fd6_4b0c_cbf8_4bad,
+    0x1317_e5ef_3ab3_2700_5de5_eee7_ff7b_2f4c,
+    0x17dd_df6b_095f_f0c0_755f_6aa1_ff59_fb1f,
+    0x1dd5_5745_cbb7_ecf0_92b7_454a_7f30_79e7,
+    0x12a5_568b_9f52_f416_5bb2_8b4e_8f7e_4c30,
+    0x174e_ac2e_8727_b11b_f29f_2e22_335d_df3c,
+    0x1d22_573a_28f1_9d62_ef46_f9aa_c035_570b,
+    0x1235_7684_5997_025d_d58c_5c0a_b821_5667,
+    0x16c2_d425_6ffc_c2f5_4aef_730d_6629_ac01,
+    0x1c73_892e_cbfb_f3b2_9dab_4fd0_bfb4_1701,
+    0x11c8_35bd_3f7d_784f_a28b_11e2_77d0_8e60,
+    0x163a_432c_8f5c_d663_8b2d_d65b_15c4_b1f9,
+    0x1bc8_d3f7_b334_0bfc_6df9_4bf1_db35_de77,
+    0x115d_847a_d000_877d_c4bb_cf77_2901_ab0a,
+    0x15b4_e599_8400_a95d_35ea_c354_f342_15cd,
+    0x1b22_1eff_e500_d3b4_8365_742a_3012_9b40,
+    0x10f5_535f_ef20_8450_d21f_689a_5e0b_a108,
+    0x1532_a837_eae8_a565_06a7_42c0_f58e_894a,
+    0x1a7f_5245_e5a2_cebe_4851_1371_32f2_2b9d,
+    0x108f_936b_af85_c136_ed32_ac26_bfd7_5b42,
+    0x14b3_7846_9b67_3184_a87f_5730_6fcd_3212,
+    0x19e0_5658_4240_fde5_d29f_2cfc_8bc0_7e97,
+    0x102c_35f7_2968_9eaf_a3a3_7c1d_d758_4f1e,
+    0x1437_4374_f3c2_c65b_8c8c_5b25_4d2e_62e6,
+    0x1945_1452_30b3_77f2_6faf_71ee_a079_fb9f,
+    0x1f96_5966_bce0_55ef_0b9b_4e6a_4898_7a87,
+    0x13bd_f7e0_360c_35b5_6741_1102_6d5f_4c94,
+    0x18ad_75d8_438f_4322_c111_5543_08b7_1fba,
+    0x1ed8_d34e_5473_13eb_7155_aa93_cae4_e7a8,
+    0x1347_8410_f4c7_ec73_26d5_8a9c_5ecf_10c9,
+    0x1819_6515_31f9_e78f_f08a_ed43_7682_d4fb,
+    0x1e1f_be5a_7e78_6173_ecad_a894_5423_8a3a,
+    0x12d3_d6f8_8f0b_3ce8_73ec_895c_b496_3664,
+    0x1788_ccb6_b2ce_0c22_90e7_abb3_e1bb_c3fd,
+    0x1d6a_ffe4_5f81_8f2b_3521_96a0_da2a_b4fd,
+    0x1262_dfee_bbb0_f97b_0134_fe24_885a_b11e,
+    0x16fb_97ea_6a9d_37d9_c182_3dad_aa71_5d65,
+    0x1cba_7de5_0544_85d0_31e2_cd19_150d_b4bf,
+    0x11f4_8eaf_234a_d3a2_1f2d_c02f_ad28_90f7,
+    0x1671_b25a_ec1d_888a_a6f9_303b_9872_b535,
+    0x1c0e_1ef1_a724_eaad_50b7_7c4a_7e8f_6282,
+    0x1188_d357_0877_12ac_5272_adae_8f19_9d91,
+    0x15eb_082c_ca94_d757_670f_591a_32e0_04f6,
+    0x1b65_ca37_fd3a_0d2d_40d3_2f60_bf98_0633,
+    0x111f_9e62_fe44_483c_4883_fd9c_77bf_03e0,
+    0x1567_85fb_bdd5_5a4b_5aa4_fd03_95ae_c4d8,
+    0x1ac1_677a_ad4a_b0de_314e_3c44_7b1a_760e,
+    0x10b8_e0ac_ac4e_ae8a_ded0_e5aa_ccf0_89c9,
+    0x14e7_18d7_d762_5a2d_9685_1f15_802c_ac3b,
+    0x1a20_df0d_cd3a_f0b8_fc26_66da_e037_d74a,
+    0x1054_8b68_a044_d673_9d98_0048_cc22_e68e,
+    0x1469_ae42_c856_0c10_84fe_005a_ff2b_a032,
+    0x1984_19d3_7a6b_8f14_a63d_8071_bef6_883e,
+    0x1fe5_2048_5906_72d9_cfcc_e08e_2eb4_2a4e,
+    0x13ef_342d_37a4_07c8_21e0_0c58_dd30_9a70,
+    0x18eb_0138_858d_09ba_2a58_0f6f_147c_c10d,
+    0x1f25_c186_a6f0_4c28_b4ee_134a_d99b_f150,
+    0x1377_98f4_2856_2f99_7114_cc0e_c801_76d2,
+    0x1855_7f31_326b_bb7f_cd59_ff12_7a01_d486,
+    0x1e6a_defd_7f06_aa5f_c0b0_7ed7_1882_49a8,
+    0x1302_cb5e_6f64_2a7b_d86e_4f46_6f51_6e09,
+    0x17c3_7e36_0b3d_351a_ce89_e318_0b25_c98b,
+    0x1db4_5dc3_8e0c_8261_822c_5bde_0def_3bee,
+    0x1290_ba9a_38c7_d17c_f15b_b96a_c8b5_8575,
+    0x1734_e940_c6f9_c5dc_2db2_a7c5_7ae2_e6d2,
+    0x1d02_2390_f8b8_3753_391f_51b6_d99b_a086,
+    0x1221_563a_9b73_2294_03b3_9312_4801_4454,
+    0x16a9_abc9_424f_eb39_04a0_77d6_da01_9569,
+    0x1c54_16bb_92e3_e607_45c8_95cc_9081_fac3,
+    0x11b4_8e35_3bce_6fc4_8b9d_5d9f_da51_3cba,
+    0x1621_b1c2_8ac2_0bb5_ae84_b507_d0e5_8be8,
+    0x1baa_1e33_2d72_8ea3_1a25_e249_c51e_eee3,
+    0x114a_52df_fc67_9925_f057_ad6e_1b33_554d,
+    0x159c_e797_fb81_7f6f_6c6d_98c9_a200_2aa1,
+    0x1b04_217d_fa61_df4b_4788_fefc_0a80_3549,
+    0x10e2_94ee_bc7d_2b8f_0cb5_9f5d_8690_214e,
+    0x151b_3a2a_6b9c_7672_cfe3_0734_e834_29a1,
+    0x1a62_08b5_0683_940f_83db_c902_2241_340a,
+    0x107d_4571_2412_3c89_b269_5da1_5568_c086,
+    0x149c_96cd_6d16_cbac_1f03_b509_aac2_f0a7,
+    0x19c3_bc80_c85c_7e97_26c4_a24c_1573_acd1,
+    0x101a_55d0_7d39_cf1e_783a_e56f_8d68_4c03,
+    0x1420_eb44_9c88_42e6_1649_9ecb_70c2_5f03,
+    0x1929_2615_c3aa_539f_9bdc_067e_4cf2_f6c4,
+    0x1f73_6f9b_3494_e887_82d3_081d_e02f_b476,
+    0x13a8_25c1_00dd_1154_b1c3_e512_ac1d_d0c9,
+    0x1892_2f31_4114_55a9_de34_de57_5725_44fc,
+    0x1eb6_bafd_9159_6b14_55c2_15ed_2cee_963b,
+    0x1332_34de_7ad7_e2ec_b599_4db4_3c15_1de5,
+    0x17fe_c216_198d_dba7_e2ff_a121_4b1a_655e,
+    0x1dfe_729b_9ff1_5291_dbbf_8969_9de0_feb6,
+    0x12bf_07a1_43f6_d39b_2957_b5e2_02ac_9f31,
+    0x176e_c989_94f4_8881_f3ad_a35a_8357_c6fe,
+    0x1d4a_7beb_fa31_aaa2_7099_0c31_242d_b8bd,
+    0x124e_8d73_7c5f_0aa5_865f_a79e_b69c_9376,
+    0x16e2_30d0_5b76_cd4e_e7f7_9186_6443_b854,
+    0x1c9a_bd04_7254_80a2_a1f5_75e7_fd54_a669,
+    0x11e0_b622_c774_d065_a539_69b0_fe54_e801,
+    0x1658_e3ab_7952_047f_0e87_c41d_3dea_2202,
+    0x1bef_1c96_57a6_859e_d229_b524_8d64_aa82,
+    0x1175_71dd_f6c8_1383_435a_1136_d85e_ea91,
+    0x15d2_ce55_747a_1864_1430_9584_8e76_a536,
+    0x1b47_81ea_d198_9e7d_193c_bae5_b214_4e83,
+    0x110c_b132_c2ff_630e_2fc5_f4cf_8f4c_b112,
+    0x154f_dd7f_73bf_3bd1_bbb7_7203_731f_dd56,
+    0x1aa3_d4df_50af_0ac6_2aa5_4e84_4fe7_d4ac,
+    0x10a6_650b_926d_66bb_daa7_5112_b1f0_e4eb,
+    0x14cf_fe4e_7708_c06a_d151_2557_5e6d_1e26,
+    0x1a03_fde2_14ca_f085_85a5_6ead_3608_65b0,
+    0x1042_7ead_4cfe_d653_7387_652c_41c5_3f8e,
+    0x1453_1e58_a03e_8be8_5069_3e77_5236_8f71,
+    0x1967_e5ee_c84e_2ee2_6483_8e15_26c4_334e,
+    0x1fc1_df6a_7a61_ba9a_fda4_719a_7075_4022,
+    0x13d9_2ba2_8c7d_14a0_de86_c700_8649_4815,
+    0x18cf_768b_2f9c_59c9_1628_78c0_a7db_9a1a,
+    0x1f03_542d_fb83_703b_5bb2_96f0_d1d2_80a1,
+    0x1362_149c_bd32_2625_194f_9e56_8323_9064,
+    0x183a_99c3_ec7e_afae_5fa3_85ec_23ec_747e,
+    0x1e49_4034_e79e_5b99_f78c_6767_2ce7_919d,
+    0x12ed_c821_10c2_f940_3ab7_c0a0_7c10_bb02,
+    0x17a9_3a29_54f3_b790_4965_b0c8_9b14_e9c3,
+    0x1d93_88b3_aa30_a574_5bbf_1cfa_c1da_2433,
+    0x127c_3570_4a5e_6768_b957_721c_b928_56a0,
+    0x171b_42cc_5cf6_0142_e7ad_4ea3_e772_6c48,
+    0x1ce2_137f_7433_8193_a198_a24c_e14f_075a,
+    0x120d_4c2f_a8a0_30fc_44ff_6570_0cd1_6498,
+    0x1690_9f3b_92c8_3d3b_563f_3ecc_1005_bdbe,
+    0x1c34_c70a_777a_4c8a_2bcf_0e7f_1407_2d2e,
+    0x11a0_fc66_8aac_6fd6_5b61_690f_6c84_7c3d,
+    0x1609_3b80_2d57_8bcb_f239_c353_47a5_9b4c,
+    0x1b8b_8a60_38ad_6ebe_eec8_3428_198f_021f,
+    0x1137_367c_236c_6537_553d_2099_0ff9_6153,
+    0x1585_041b_2c47_7e85_2a8c_68bf_53f7_b9a8,
+    0x1ae6_4521_f759_5e26_752f_82ef_28f5_a812,
+    0x10cf_eb35_3a97_dad8_093d_b1d5_7999_890b,
+    0x1503_e602_893d_d18e_0b8d_1e4a_d7ff_eb4e,
+    0x1a44_df83_2b8d_45f1_8e70_65dd_8dff_e622,
+    0x106b_0bb1_fb38_4bb6_f906_3faa_78bf_efd5,
+    0x1485_ce9e_7a06_5ea4_b747_cf95_16ef_ebca,
+    0x19a7_4246_1887_f64d_e519_c37a_5cab_e6bd,
+    0x1008_896b_cf54_f9f0_af30_1a2c_79eb_7036,
+    0x140a_abc6_c32a_386c_dafc_20b7_9866_4c43,
+    0x190d_56b8_73f4_c688_11bb_28e5_7e7f_df54,
+    0x1f50_ac66_90f1_f82a_1629_f31e_de1f_d72a,
+    0x1392_6bc0_1a97_3b1a_4dda_37f3_4ad3_e67a,
+    0x1877_06b0_213d_09e0_e150_c5f0_1d88_e019,
+    0x1e94_c85c_298c_4c59_19a4_f76c_24eb_181f,
+    0x131c_fd39_99f7_afb7_b007_1aa3_9712_ef13,
+    0x17e4_3c88_0075_9ba5_9c08_e14c_7cd7_aad8,
+    0x1ddd_4baa_0093_028f_030b_199f_9c0d_958e,
+    0x12aa_4f4a_405b_e199_61e6_f003_c188_7d79,
+    0x1754_e31c_d072_d9ff_ba60_ac04_b1ea_9cd7,
+    0x1d2a_1be4_048f_907f_a8f8_d705_de65_440d,
+    0x123a_516e_82d9_ba4f_c99b_8663_aaff_4a88,
+    0x16c8_e5ca_2390_28e3_bc02_67fc_95bf_1d2a,
+    0x1c7b_1f3c_ac74_331c_ab03_01fb_bb2e_e474,
+    0x11cc_f385_ebc8_9ff1_eae1_e13d_54fd_4ec9,
+    0x1640_3067_66ba_c7ee_659a_598c_aa3c_a27b,
+    0x1bd0_3c81_4069_79e9_ff00_efef_d4cb_cb1a,
+    0x1162_25d0_c841_ec32_3f60_95f5_e4ff_5ef0,
+    0x15ba_af44_fa52_673e_cf38_bb73_5e3f_36ac,
+    0x1b29_5b16_38e7_010e_8306_ea50_35cf_0457,
+    0x10f9_d8ed_e390_60a9_11e4_5272_21a1_62b6,
+    0x1538_4f29_5c74_78d3_565d_670e_aa09_bb64,
+    0x1a86_62f3_b391_9708_2bf4_c0d2_548c_2a3d,
+    0x1093_fdd8_503a_fe65_1b78_f883_74d7_9a66,
+    0x14b8_fd4e_6449_bdfe_6257_36a4_520d_8100,
+    0x19e7_3ca1_fd5c_2d7d_faed_044d_6690_e140,
+    0x1030_85e5_3e59_9c6e_bcd4_22b0_601a_8cc8,
+    0x143c_a75e_8df0_038a_6c09_2b5c_7821_2ffa,
+    0x194b_d136_316c_046d_070b_7633_9629_7bf8,
+    0x1f9e_c583_bdc7_0588_48ce_53c0_7bb3_daf6,
+    0x13c3_3b72_569c_6375_2d80_f458_4d50_68da,
+    0x18b4_0a4e_ec43_7c52_78e1_316e_60a4_8310,
+];
+
+pub const FLOAT_POW5_INV_SPLIT: [u64; 31] = [
+    0x0800_0000_0000_0001,
+    0x0666_6666_6666_6667,
+    0x051e_b851_eb85_1eb9,
+    0x0418_9374_bc6a_7efa,
+    0x068d_b8ba_c710_cb2a,
+    0x053e_2d62_38da_3c22,
+    0x0431_bde8_2d7b_634e,
+    0x06b5_fca6_af2b_d216,
+    0x055e_63b8_8c23_0e78,
+    0x044b_82fa_09b5_a52d,
+    0x06df_37f6_75ef_6eae,
+    0x057f_5ff8_5e59_2558,
+    0x0465_e660_4b7a_8447,
+    0x0709_709a_125d_a071,
+    0x05a1_26e1_a84a_e6c1,
+    0x0480_ebe7_b9d5_8567,
+    0x0734_aca5_f622_6f0b,
+    0x05c3_bd51_91b5_25a3,
+    0x049c_9774_7490_eae9,
+    0x0760_f253_edb4_ab0e,
+    0x05e7_2843_2490_88d8,
+    0x04b8_ed02_83a6_d3e0,
+    0x078e_4804_05d7_b966,
+    0x060b_6cd0_04ac_9452,
+    0x04d5_f0a6_6a23_a9db,
+    0x07bc_b43d_769f_762b,
+    0x0630_9031_2bb2_c4ef,
+    0x04f3_a68d_bc8f_03f3,
+    0x07ec_3daf_9418_0651,
+    0x0656_97bf_a9ac_d1da,
+    0x0512_12ff_baf0_a7e2,
+];
+
+pub const FLOAT_POW5_SPLIT: [u64; 47] = [
+    0x1000_0000_0000_0000,
+    0x1400_0000_0000_0000,
+    0x1900_0000_0000_0000,
+    0x1f40_0000_0000_0000,
+    0x1388_0000_0000_0000,
+    0x186a_0000_0000_0000,
+    0x1e84_8000_0000_0000,
+    0x1312_d000_0000_0000,
+    0x17d7_8400_0000_0000,
+    0x1dcd_6500_0000_0000,
+    0x12a0_5f20_0000_0000,
+    0x1748_76e8_0000_0000,
+    0x1d1a_94a2_0000_0000,
+    0x1230_9ce5_4000_0000,
+    0x16bc_c41e_9000_0000,
+    0x1c6b_f526_3400_0000,
+    0x11c3_7937_e080_0000,
+    0x1634_5785_d8a0_0000,
+    0x1bc1_6d67_4ec8_0000,
+    0x1158_e460_913d_0000,
+    0x15af_1d78_b58c_4000,
+    0x1b1a_e4d6_e2ef_5000,
+    0x10f0_cf06_4dd5_9200,
+    0x152d_02c7_e14a_f680,
+    0x1a78_4379_d99d_b420,
+    0x108b_2a2c_2802_9094,
+    0x14ad_f4b7_3203_34b9,
+    0x19d9_71e4_fe84_01e7,
+    0x1027_e72f_1f12_8130,
+    0x1431_e0fa_e6d7_217c,
+    0x193e_5939_a08c_e9db,
+    0x1f8d_ef88_08b0_2452,
+    0x13b8_b5b5_056e_16b3,
+    0x18a6_e322_46c9_9c60,
+    0x1ed0_9bea_d87c_0378,
+    0x1342_6172_c74d_822b,
+    0x1812_f9cf_7920_e2b6,
+    0x1e17_b843_5769_1b64,
+    0x12ce_d32a_16a1_b11e,
+    0x1782_87f4_9c4a_1d66,
+    0x1d63_29f1_c35c_a4bf,
+    0x125d_fa37_1a19_e6f7,
+    0x16f5_78c4_e0a0_60b5,
+    0x1cb2_d6f6_18c8_78e3,
+    0x11ef_c659_cf7d_4b8d,
+    0x166b_b7f0_435c_9e71,
+    0x1c06_a5ec_5433_c60d,
+];
@@ -6,6 +6,7 @@ pub trait GenericString {
 
     fn len(&self) -> usize;
     fn capacity(&self) -> usize;
+    fn as_str(&self) -> &str;
     unsafe fn as_mut_vec(&mut self) -> &mut Self::InnerVec;
 }
 
@@ -22,6 +23,11 @@ impl GenericString for String {
         self.capacity()
     }
 
+    #[inline]
+    fn as_str(&self) -> &str {
+        self.as_str()
+    }
+
     #[inline]
     unsafe fn as_mut_vec(&mut self) -> &mut Self::InnerVec {
         self.as_mut_vec()
@@ -42,6 +48,11 @@ impl<const N: usize> GenericString for heapless::String<N> {
         self.capacity()
     }
 
+    #[inline]
+    fn as_str(&self) -> &str {
+        self.as_str()
+    }
+
     #[inline]
     unsafe fn as_mut_vec(&mut self) -> &mut Self::InnerVec {
         self.as_mut_vec()
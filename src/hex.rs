@@ -0,0 +1,108 @@
+use super::{ascii_buf::AsciiBuf, generic_vec::GenericVec};
+
+/// A trait for hex encoding without the capacity check.
+pub trait PushHexUnchecked {
+    /// Appends `bytes` encoded as lowercase hex digits without the capacity
+    /// check.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the collection must be sufficient for the
+    /// `2 * bytes.len()` new bytes.
+    unsafe fn push_hex_unchecked(&mut self, bytes: &[u8]);
+
+    /// Appends `bytes` encoded as uppercase hex digits without the capacity
+    /// check.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the collection must be sufficient for the
+    /// `2 * bytes.len()` new bytes.
+    unsafe fn push_hex_upper_unchecked(&mut self, bytes: &[u8]);
+}
+
+impl<B: AsciiBuf> PushHexUnchecked for B {
+    #[inline]
+    unsafe fn push_hex_unchecked(&mut self, bytes: &[u8]) {
+        push_hex(self.as_mut_byte_vec(), bytes, &HEX_LOWER);
+    }
+
+    #[inline]
+    unsafe fn push_hex_upper_unchecked(&mut self, bytes: &[u8]) {
+        push_hex(self.as_mut_byte_vec(), bytes, &HEX_UPPER);
+    }
+}
+
+#[inline]
+unsafe fn push_hex<V: GenericVec<Item = u8>>(v: &mut V, bytes: &[u8], table: &[[u8; 2]; 256]) {
+    debug_assert!(bytes.len() <= (v.capacity() - v.len()) / 2);
+    let dst = v.as_mut_ptr().add(v.len()).cast::<[u8; 2]>();
+    for (i, &byte) in bytes.iter().enumerate() {
+        dst.add(i).write_unaligned(table[byte as usize]);
+    }
+    v.set_len(v.len() + 2 * bytes.len());
+}
+
+/// A trait for hex decoding without the capacity and validity checks.
+pub trait PushHexDecodedUnchecked {
+    /// Appends the bytes encoded as hex digits in `hex` without the capacity
+    /// check and the validity check.
+    ///
+    /// Both lowercase and uppercase digits are accepted.
+    ///
+    /// # Safety
+    ///
+    /// - `hex` must consist of an even number of hex digits
+    /// - the capacity of the collection must be sufficient for the
+    ///   `hex.len() / 2` new bytes
+    unsafe fn push_hex_decoded_unchecked(&mut self, hex: &str);
+}
+
+impl<V: GenericVec<Item = u8>> PushHexDecodedUnchecked for V {
+    #[inline]
+    unsafe fn push_hex_decoded_unchecked(&mut self, hex: &str) {
+        let hex = hex.as_bytes();
+        debug_assert!(hex.len().is_multiple_of(2));
+        debug_assert!(hex.iter().all(u8::is_ascii_hexdigit));
+        let len = hex.len() / 2;
+        debug_assert!(len <= self.capacity() - self.len());
+
+        let dst = self.as_mut_ptr().add(self.len());
+        for i in 0..len {
+            let hi = HEX_DECODE[*hex.get_unchecked(2 * i) as usize];
+            let lo = HEX_DECODE[*hex.get_unchecked(2 * i + 1) as usize];
+            *dst.add(i) = hi << 4 | lo;
+        }
+        self.set_len(self.len() + len);
+    }
+}
+
+const fn hex_table(digits: &[u8; 16]) -> [[u8; 2]; 256] {
+    let mut table = [[0; 2]; 256];
+    let mut i = 0;
+    while i < 256 {
+        table[i] = [digits[i >> 4], digits[i & 0xF]];
+        i += 1;
+    }
+    table
+}
+
+static HEX_LOWER: [[u8; 2]; 256] = hex_table(b"0123456789abcdef");
+pub static HEX_UPPER: [[u8; 2]; 256] = hex_table(b"0123456789ABCDEF");
+
+/// Maps ASCII hex digits to their values and everything else to 0.
+pub static HEX_DECODE: [u8; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 10 {
+        table[(b'0' + i) as usize] = i;
+        i += 1;
+    }
+    let mut i = 0;
+    while i < 6 {
+        table[(b'a' + i) as usize] = 10 + i;
+        table[(b'A' + i) as usize] = 10 + i;
+        i += 1;
+    }
+    table
+};
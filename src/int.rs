@@ -0,0 +1,373 @@
+use super::{ascii_buf::AsciiBuf, generic_vec::GenericVec};
+
+/// A primitive integer type that can be pushed with [`PushIntUnchecked`].
+pub trait Int: Copy + private::IntRepr {
+    /// The maximum length of the decimal representation, including the sign.
+    const MAX_DECIMAL_LEN: usize;
+    /// The maximum length of the binary representation.
+    const MAX_BINARY_LEN: usize;
+    /// The maximum length of the octal representation.
+    const MAX_OCTAL_LEN: usize;
+    /// The maximum length of the hexadecimal representation.
+    const MAX_HEX_LEN: usize;
+}
+
+/// A trait for pushing integers as ASCII text without the capacity check.
+pub trait PushIntUnchecked<I: Int> {
+    /// Appends the decimal representation of `value`, as formatted by `{}`,
+    /// without the capacity check.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the collection must be sufficient for the new bytes,
+    /// which is guaranteed by [`Int::MAX_DECIMAL_LEN`] spare bytes.
+    unsafe fn push_int_unchecked(&mut self, value: I);
+
+    /// Appends the decimal representation of `value` zero-padded to `width`
+    /// bytes, as formatted by `{:0width$}`, without the capacity check.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the collection must be sufficient for the new bytes,
+    /// which is guaranteed by `max(width, I::MAX_DECIMAL_LEN)` spare bytes.
+    unsafe fn push_int_padded_unchecked(&mut self, value: I, width: usize);
+
+    /// Appends the binary representation of `value`, as formatted by
+    /// `{:b}`, without the capacity check.
+    ///
+    /// Negative values are written in two's complement.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the collection must be sufficient for the new bytes,
+    /// which is guaranteed by [`Int::MAX_BINARY_LEN`] spare bytes.
+    unsafe fn push_int_bin_unchecked(&mut self, value: I);
+
+    /// Appends the binary representation of `value` zero-padded to `width`
+    /// bytes, as formatted by `{:0width$b}`, without the capacity check.
+    ///
+    /// Negative values are written in two's complement.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the collection must be sufficient for the new bytes,
+    /// which is guaranteed by `max(width, I::MAX_BINARY_LEN)` spare bytes.
+    unsafe fn push_int_bin_padded_unchecked(&mut self, value: I, width: usize);
+
+    /// Appends the octal representation of `value`, as formatted by
+    /// `{:o}`, without the capacity check.
+    ///
+    /// Negative values are written in two's complement.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the collection must be sufficient for the new bytes,
+    /// which is guaranteed by [`Int::MAX_OCTAL_LEN`] spare bytes.
+    unsafe fn push_int_oct_unchecked(&mut self, value: I);
+
+    /// Appends the octal representation of `value` zero-padded to `width`
+    /// bytes, as formatted by `{:0width$o}`, without the capacity check.
+    ///
+    /// Negative values are written in two's complement.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the collection must be sufficient for the new bytes,
+    /// which is guaranteed by `max(width, I::MAX_OCTAL_LEN)` spare bytes.
+    unsafe fn push_int_oct_padded_unchecked(&mut self, value: I, width: usize);
+
+    /// Appends the lowercase hexadecimal representation of `value`, as
+    /// formatted by `{:x}`, without the capacity check.
+    ///
+    /// Negative values are written in two's complement.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the collection must be sufficient for the new bytes,
+    /// which is guaranteed by [`Int::MAX_HEX_LEN`] spare bytes.
+    unsafe fn push_int_hex_unchecked(&mut self, value: I);
+
+    /// Appends the lowercase hexadecimal representation of `value` zero-padded
+    /// to `width` bytes, as formatted by `{:0width$x}`, without the capacity
+    /// check.
+    ///
+    /// Negative values are written in two's complement.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the collection must be sufficient for the new bytes,
+    /// which is guaranteed by `max(width, I::MAX_HEX_LEN)` spare bytes.
+    unsafe fn push_int_hex_padded_unchecked(&mut self, value: I, width: usize);
+}
+
+impl<B: AsciiBuf, I: Int> PushIntUnchecked<I> for B {
+    #[inline]
+    unsafe fn push_int_unchecked(&mut self, value: I) {
+        self.push_int_padded_unchecked(value, 0);
+    }
+
+    #[inline]
+    unsafe fn push_int_padded_unchecked(&mut self, value: I, width: usize) {
+        let v = self.as_mut_byte_vec();
+        let negative = value.is_negative();
+        let abs = value.unsigned_abs();
+        let n_digits = abs.decimal_len();
+        let len = width.max(usize::from(negative) + n_digits);
+        debug_assert!(len <= v.capacity() - v.len());
+
+        let dst = v.as_mut_ptr().add(v.len());
+        if negative {
+            *dst = b'-';
+        }
+        let n_zeros = len - usize::from(negative) - n_digits;
+        core::ptr::write_bytes(dst.add(usize::from(negative)), b'0', n_zeros);
+        abs.write_decimal(dst.add(len));
+        v.set_len(v.len() + len);
+    }
+
+    #[inline]
+    unsafe fn push_int_bin_unchecked(&mut self, value: I) {
+        push_int_radix_padded_unchecked(self, value, 1, 0);
+    }
+
+    #[inline]
+    unsafe fn push_int_bin_padded_unchecked(&mut self, value: I, width: usize) {
+        push_int_radix_padded_unchecked(self, value, 1, width);
+    }
+
+    #[inline]
+    unsafe fn push_int_oct_unchecked(&mut self, value: I) {
+        push_int_radix_padded_unchecked(self, value, 3, 0);
+    }
+
+    #[inline]
+    unsafe fn push_int_oct_padded_unchecked(&mut self, value: I, width: usize) {
+        push_int_radix_padded_unchecked(self, value, 3, width);
+    }
+
+    #[inline]
+    unsafe fn push_int_hex_unchecked(&mut self, value: I) {
+        push_int_radix_padded_unchecked(self, value, 4, 0);
+    }
+
+    #[inline]
+    unsafe fn push_int_hex_padded_unchecked(&mut self, value: I, width: usize) {
+        push_int_radix_padded_unchecked(self, value, 4, width);
+    }
+}
+
+/// Pushes `value` in the radix `1 << shift`, which must be 2, 8 or 16.
+#[inline]
+unsafe fn push_int_radix_padded_unchecked<B: AsciiBuf, I: Int>(
+    buf: &mut B,
+    value: I,
+    shift: u32,
+    width: usize,
+) {
+    let v = buf.as_mut_byte_vec();
+    let bits = value.to_bits();
+    let n_digits = bits.radix_len(shift);
+    let len = width.max(n_digits);
+    debug_assert!(len <= v.capacity() - v.len());
+
+    let dst = v.as_mut_ptr().add(v.len());
+    core::ptr::write_bytes(dst, b'0', len - n_digits);
+    bits.write_radix(shift, dst.add(len));
+    v.set_len(v.len() + len);
+}
+
+mod private {
+    pub trait IntRepr {
+        type Wide: super::Digits;
+
+        fn is_negative(&self) -> bool;
+        fn unsigned_abs(self) -> Self::Wide;
+        /// Two's complement representation, zero-extended.
+        fn to_bits(self) -> Self::Wide;
+    }
+}
+
+/// An unsigned integer that the digits are actually computed for.
+pub trait Digits: Copy {
+    fn decimal_len(self) -> usize;
+    /// Writes exactly `self.decimal_len()` digits ending at `end`.
+    unsafe fn write_decimal(self, end: *mut u8);
+    fn radix_len(self, shift: u32) -> usize;
+    /// Writes exactly `self.radix_len(shift)` digits ending at `end`.
+    unsafe fn write_radix(self, shift: u32, end: *mut u8);
+}
+
+const DEC_DIGITS_LUT: &[u8; 200] = b"\
+    0001020304050607080910111213141516171819\
+    2021222324252627282930313233343536373839\
+    4041424344454647484950515253545556575859\
+    6061626364656667686970717273747576777879\
+    8081828384858687888990919293949596979899";
+
+const RADIX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Writes the digits of `n` ending at `end` and returns the start pointer.
+#[inline]
+#[allow(clippy::cast_possible_truncation)]
+pub unsafe fn write_u64_digits(mut n: u64, mut end: *mut u8) -> *mut u8 {
+    while n >= 100 {
+        let d = (n % 100) as usize * 2;
+        n /= 100;
+        end = end.sub(2);
+        core::ptr::copy_nonoverlapping(DEC_DIGITS_LUT.as_ptr().add(d), end, 2);
+    }
+    if n >= 10 {
+        end = end.sub(2);
+        core::ptr::copy_nonoverlapping(DEC_DIGITS_LUT.as_ptr().add(n as usize * 2), end, 2);
+    } else {
+        end = end.sub(1);
+        *end = b'0' + n as u8;
+    }
+    end
+}
+
+impl Digits for u64 {
+    #[inline]
+    fn decimal_len(self) -> usize {
+        self.checked_ilog10().map_or(1, |log| log as usize + 1)
+    }
+
+    #[inline]
+    unsafe fn write_decimal(self, end: *mut u8) {
+        write_u64_digits(self, end);
+    }
+
+    #[inline]
+    fn radix_len(self, shift: u32) -> usize {
+        ((Self::BITS - self.leading_zeros()).div_ceil(shift) as usize).max(1)
+    }
+
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)]
+    unsafe fn write_radix(mut self, shift: u32, mut end: *mut u8) {
+        let mask = (1 << shift) - 1;
+        loop {
+            end = end.sub(1);
+            *end = RADIX_DIGITS[(self & mask) as usize];
+            self >>= shift;
+            if self == 0 {
+                break;
+            }
+        }
+    }
+}
+
+impl Digits for u128 {
+    #[inline]
+    fn decimal_len(self) -> usize {
+        self.checked_ilog10().map_or(1, |log| log as usize + 1)
+    }
+
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)]
+    unsafe fn write_decimal(mut self, mut end: *mut u8) {
+        const CHUNK: u128 = 10_u128.pow(19);
+        while self > Self::from(u64::MAX) {
+            let rest = (self % CHUNK) as u64;
+            self /= CHUNK;
+            let start = write_u64_digits(rest, end);
+            end = end.sub(19);
+            core::ptr::write_bytes(end, b'0', start.offset_from(end).cast_unsigned());
+        }
+        write_u64_digits(self as u64, end);
+    }
+
+    #[inline]
+    fn radix_len(self, shift: u32) -> usize {
+        ((Self::BITS - self.leading_zeros()).div_ceil(shift) as usize).max(1)
+    }
+
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)]
+    unsafe fn write_radix(mut self, shift: u32, mut end: *mut u8) {
+        let mask = (1 << shift) - 1;
+        loop {
+            end = end.sub(1);
+            *end = RADIX_DIGITS[(self & mask) as usize];
+            self >>= shift;
+            if self == 0 {
+                break;
+            }
+        }
+    }
+}
+
+/// The length of the decimal representation of `n`.
+const fn decimal_len(n: u128) -> usize {
+    match n.checked_ilog10() {
+        Some(log) => log as usize + 1,
+        None => 1,
+    }
+}
+
+macro_rules! impl_unsigned {
+    ($wide:ty => $($t:ty)*) => ($(
+        impl Int for $t {
+            const MAX_DECIMAL_LEN: usize = decimal_len(<$t>::MAX as u128);
+            const MAX_BINARY_LEN: usize = <$t>::BITS as usize;
+            const MAX_OCTAL_LEN: usize = <$t>::BITS.div_ceil(3) as usize;
+            const MAX_HEX_LEN: usize = <$t>::BITS.div_ceil(4) as usize;
+        }
+
+        #[allow(clippy::cast_lossless)]
+        impl private::IntRepr for $t {
+            type Wide = $wide;
+
+            #[inline]
+            fn is_negative(&self) -> bool {
+                false
+            }
+
+            #[inline]
+            fn unsigned_abs(self) -> $wide {
+                self as $wide
+            }
+
+            #[inline]
+            fn to_bits(self) -> $wide {
+                self as $wide
+            }
+        }
+    )*)
+}
+
+macro_rules! impl_signed {
+    ($wide:ty => $($t:ty)*) => ($(
+        impl Int for $t {
+            const MAX_DECIMAL_LEN: usize = decimal_len(<$t>::MAX as u128) + 1;
+            const MAX_BINARY_LEN: usize = <$t>::BITS as usize;
+            const MAX_OCTAL_LEN: usize = <$t>::BITS.div_ceil(3) as usize;
+            const MAX_HEX_LEN: usize = <$t>::BITS.div_ceil(4) as usize;
+        }
+
+        #[allow(clippy::cast_lossless)]
+        impl private::IntRepr for $t {
+            type Wide = $wide;
+
+            #[inline]
+            fn is_negative(&self) -> bool {
+                *self < 0
+            }
+
+            #[inline]
+            fn unsigned_abs(self) -> $wide {
+                self.unsigned_abs() as $wide
+            }
+
+            #[inline]
+            fn to_bits(self) -> $wide {
+                self.cast_unsigned() as $wide
+            }
+        }
+    )*)
+}
+
+impl_unsigned! { u64 => u8 u16 u32 u64 usize }
+impl_unsigned! { u128 => u128 }
+impl_signed! { u64 => i8 i16 i32 i64 isize }
+impl_signed! { u128 => i128 }
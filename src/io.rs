@@ -0,0 +1,69 @@
+use super::{generic_vec::GenericVec, ExtendFromSliceUnchecked};
+use std::io::{self, IoSlice};
+
+/// A [`io::Write`] adapter that appends to a byte vector without the
+/// capacity checks.
+///
+/// It works with `Vec<u8>` and, with the `heapless` feature,
+/// `heapless::Vec<u8, N>`. The byte budget promised on construction is
+/// enforced in debug builds.
+#[derive(Debug)]
+pub struct UncheckedIoWriter<'a, V: GenericVec<Item = u8> + ExtendFromSliceUnchecked<u8>> {
+    vec: &'a mut V,
+    end: usize,
+}
+
+impl<'a, V: GenericVec<Item = u8> + ExtendFromSliceUnchecked<u8>> UncheckedIoWriter<'a, V> {
+    /// Creates a writer that appends to `vec`.
+    ///
+    /// # Safety
+    ///
+    /// - `budget` must be `<= vec.capacity() - vec.len()`
+    /// - at most `budget` bytes must be written through the writer
+    #[inline]
+    pub unsafe fn new(vec: &'a mut V, budget: usize) -> Self {
+        debug_assert!(budget <= vec.capacity() - vec.len());
+        let end = vec.len() + budget;
+        Self { vec, end }
+    }
+
+    /// Returns the number of bytes that can still be written.
+    #[inline]
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.end - self.vec.len()
+    }
+}
+
+impl<V: GenericVec<Item = u8> + ExtendFromSliceUnchecked<u8>> io::Write
+    for UncheckedIoWriter<'_, V>
+{
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_all(buf)?;
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        let mut len = 0;
+        for buf in bufs {
+            self.write_all(buf)?;
+            len += buf.len();
+        }
+        Ok(len)
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        debug_assert!(buf.len() <= self.remaining());
+        // SAFETY: the budget promised in `new` covers the write
+        unsafe { self.vec.extend_from_slice_unchecked(buf) };
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use super::{generic_string::GenericString, generic_vec::GenericVec};
+
+/// A trait for pushing ISO-8859-1 (Latin-1) text into a string without the
+/// capacity check.
+pub trait PushLatin1Unchecked {
+    /// Appends the Latin-1 bytes `latin1` transcoded to UTF-8, like
+    /// `extend(latin1.iter().map(|&b| char::from(b)))`, without the capacity
+    /// check.
+    ///
+    /// Every byte is a valid Latin-1 character, so no validation is needed.
+    /// ASCII bytes are copied as is and the others take 2 bytes, so the
+    /// output is at most twice as long as the input.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the string must be sufficient for the new bytes, which
+    /// is exactly [`latin1_utf8_len(latin1)`](latin1_utf8_len) and at most
+    /// `2 * latin1.len()`.
+    unsafe fn push_latin1_unchecked(&mut self, latin1: &[u8]);
+}
+
+impl<S: GenericString> PushLatin1Unchecked for S {
+    #[inline]
+    unsafe fn push_latin1_unchecked(&mut self, latin1: &[u8]) {
+        debug_assert!(latin1_utf8_len(latin1) <= self.capacity() - self.len());
+        let v = self.as_mut_vec();
+        let start = v.as_mut_ptr().add(v.len());
+        let mut dst = start;
+        let mut i = 0;
+        while i < latin1.len() {
+            let run_start = i;
+            while i + 8 <= latin1.len()
+                && latin1.as_ptr().add(i).cast::<u64>().read_unaligned() & 0x8080_8080_8080_8080
+                    == 0
+            {
+                i += 8;
+            }
+            while i < latin1.len() && latin1.get_unchecked(i).is_ascii() {
+                i += 1;
+            }
+            core::ptr::copy_nonoverlapping(latin1.as_ptr().add(run_start), dst, i - run_start);
+            dst = dst.add(i - run_start);
+
+            while i < latin1.len() && !latin1.get_unchecked(i).is_ascii() {
+                let byte = *latin1.get_unchecked(i);
+                *dst = 0b1100_0000 | byte >> 6;
+                *dst.add(1) = 0b1000_0000 | (byte & 0x3F);
+                dst = dst.add(2);
+                i += 1;
+            }
+        }
+        v.set_len(v.len() + dst.offset_from(start).cast_unsigned());
+    }
+}
+
+/// Returns the exact number of UTF-8 bytes that the Latin-1 bytes `latin1`
+/// transcode to.
+#[inline]
+#[must_use]
+pub fn latin1_utf8_len(latin1: &[u8]) -> usize {
+    latin1.len() + latin1.iter().filter(|byte| !byte.is_ascii()).count()
+}
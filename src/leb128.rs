@@ -0,0 +1,183 @@
+use super::generic_vec::GenericVec;
+
+/// An unsigned integer type that can be pushed as unsigned LEB128.
+pub trait Uleb128: Copy + private::Uleb128Repr {
+    /// The maximum length of the unsigned LEB128 encoding.
+    const MAX_LEN: usize;
+}
+
+/// A signed integer type that can be pushed as signed LEB128 or as a zigzag
+/// varint.
+pub trait Sleb128: Copy + private::Sleb128Repr {
+    /// The maximum length of both the signed LEB128 encoding and the zigzag
+    /// varint encoding.
+    const MAX_LEN: usize;
+}
+
+/// A trait for pushing LEB128 variable-length integers without the capacity
+/// check.
+pub trait PushLeb128Unchecked {
+    /// Appends `value` encoded as unsigned LEB128, which is also the
+    /// protobuf varint encoding, without the capacity check.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the collection must be sufficient for the new bytes,
+    /// which is guaranteed by [`Uleb128::MAX_LEN`] spare bytes.
+    unsafe fn push_uleb128_unchecked<U: Uleb128>(&mut self, value: U);
+
+    /// Appends `value` encoded as signed LEB128 without the capacity check.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the collection must be sufficient for the new bytes,
+    /// which is guaranteed by [`Sleb128::MAX_LEN`] spare bytes.
+    unsafe fn push_sleb128_unchecked<I: Sleb128>(&mut self, value: I);
+
+    /// Appends `value` zigzag-encoded as unsigned LEB128, like protobuf
+    /// `sint32` and `sint64` fields, without the capacity check.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the collection must be sufficient for the new bytes,
+    /// which is guaranteed by [`Sleb128::MAX_LEN`] spare bytes.
+    unsafe fn push_zigzag_varint_unchecked<I: Sleb128>(&mut self, value: I);
+}
+
+impl<V: GenericVec<Item = u8>> PushLeb128Unchecked for V {
+    #[inline]
+    unsafe fn push_uleb128_unchecked<U: Uleb128>(&mut self, value: U) {
+        debug_assert!(value.uleb128_len() <= self.capacity() - self.len());
+        let len = value.write_uleb128(self.as_mut_ptr().add(self.len()));
+        self.set_len(self.len() + len);
+    }
+
+    #[inline]
+    unsafe fn push_sleb128_unchecked<I: Sleb128>(&mut self, value: I) {
+        debug_assert!(value.sleb128_len() <= self.capacity() - self.len());
+        let len = value.write_sleb128(self.as_mut_ptr().add(self.len()));
+        self.set_len(self.len() + len);
+    }
+
+    #[inline]
+    unsafe fn push_zigzag_varint_unchecked<I: Sleb128>(&mut self, value: I) {
+        self.push_uleb128_unchecked(value.zigzag());
+    }
+}
+
+mod private {
+    pub trait Uleb128Repr {
+        fn uleb128_len(self) -> usize;
+        /// Writes the encoding to `dst` and returns its length.
+        unsafe fn write_uleb128(self, dst: *mut u8) -> usize;
+        /// Reads an encoding from the start of `src` and returns the value
+        /// and the encoding length.
+        unsafe fn read_uleb128(src: &[u8]) -> (Self, usize)
+        where
+            Self: Sized;
+    }
+
+    pub trait Sleb128Repr {
+        type Unsigned: super::Uleb128;
+
+        fn sleb128_len(self) -> usize;
+        /// Writes the encoding to `dst` and returns its length.
+        unsafe fn write_sleb128(self, dst: *mut u8) -> usize;
+        fn zigzag(self) -> Self::Unsigned;
+    }
+}
+
+macro_rules! impl_uleb128 {
+    ($($t:ty)*) => ($(
+        impl Uleb128 for $t {
+            const MAX_LEN: usize = <$t>::BITS.div_ceil(7) as usize;
+        }
+
+        impl private::Uleb128Repr for $t {
+            #[inline]
+            fn uleb128_len(self) -> usize {
+                (<$t>::BITS - self.leading_zeros()).max(1).div_ceil(7) as usize
+            }
+
+            #[inline]
+            #[allow(clippy::cast_possible_truncation)]
+            unsafe fn write_uleb128(mut self, dst: *mut u8) -> usize {
+                let mut len = 0;
+                while self >= 0x80 {
+                    *dst.add(len) = self as u8 | 0x80;
+                    self >>= 7;
+                    len += 1;
+                }
+                *dst.add(len) = self as u8;
+                len + 1
+            }
+
+            #[inline]
+            unsafe fn read_uleb128(src: &[u8]) -> (Self, usize) {
+                let mut value: Self = 0;
+                let mut shift = 0;
+                let mut len = 0;
+                loop {
+                    debug_assert!(len < src.len());
+                    debug_assert!(shift < <$t>::BITS);
+                    let byte = *src.get_unchecked(len);
+                    len += 1;
+                    value |= Self::from(byte & 0x7F) << shift;
+                    if byte < 0x80 {
+                        debug_assert!(
+                            u32::from(byte) >> (<$t>::BITS - shift).min(7) == 0
+                        );
+                        return (value, len);
+                    }
+                    shift += 7;
+                }
+            }
+        }
+    )*)
+}
+
+macro_rules! impl_sleb128 {
+    ($($t:ty: $u:ty)*) => ($(
+        impl Sleb128 for $t {
+            const MAX_LEN: usize = <$t>::BITS.div_ceil(7) as usize;
+        }
+
+        impl private::Sleb128Repr for $t {
+            type Unsigned = $u;
+
+            #[inline]
+            fn sleb128_len(self) -> usize {
+                let sign_bits = if self < 0 {
+                    self.leading_ones()
+                } else {
+                    self.leading_zeros()
+                };
+                (<$t>::BITS - sign_bits + 1).div_ceil(7) as usize
+            }
+
+            #[inline]
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            unsafe fn write_sleb128(mut self, dst: *mut u8) -> usize {
+                let mut len = 0;
+                loop {
+                    let byte = self as u8 & 0x7F;
+                    self >>= 7;
+                    if (self == 0 && byte & 0x40 == 0) || (self == -1 && byte & 0x40 != 0) {
+                        *dst.add(len) = byte;
+                        return len + 1;
+                    }
+                    *dst.add(len) = byte | 0x80;
+                    len += 1;
+                }
+            }
+
+            #[inline]
+            fn zigzag(self) -> $u {
+                ((self << 1) ^ (self >> (<$t>::BITS - 1))).cast_unsigned()
+            }
+        }
+    )*)
+}
+
+impl_uleb128! { u8 u16 u32 u64 u128 usize }
+impl_sleb128! { i8: u8 i16: u16 i32: u32 i64: u64 i128: u128 isize: usize }
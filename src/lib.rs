@@ -3,11 +3,61 @@
 #![no_std]
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+pub use self::{
+    ascii::PushAsciiUnchecked,
+    base64::{Base64Config, PushBase64DecodedUnchecked, PushBase64Unchecked},
+    binary::PushBinaryUnchecked,
+    case::{
+        PushCaseMappedUnchecked, LOWERCASE_CHAR_MAX_LEN, LOWERCASE_MAX_EXPANSION,
+        UPPERCASE_CHAR_MAX_LEN, UPPERCASE_MAX_EXPANSION,
+    },
+    escape::{PushEscapedUnchecked, HTML_ESCAPE_MAX_EXPANSION, JSON_ESCAPE_MAX_EXPANSION},
+    float::{PushFloatUnchecked, F32_MAX_LEN, F64_MAX_LEN},
+    hex::{PushHexDecodedUnchecked, PushHexUnchecked},
+    int::{Int, PushIntUnchecked},
+    latin1::{latin1_utf8_len, PushLatin1Unchecked},
+    leb128::{PushLeb128Unchecked, Sleb128, Uleb128},
+    parse::{
+        parse_i128_unchecked, parse_i16_unchecked, parse_i32_unchecked, parse_i64_unchecked,
+        parse_i8_unchecked, parse_isize_unchecked, parse_u128_unchecked, parse_u16_unchecked,
+        parse_u32_unchecked, parse_u64_unchecked, parse_u8_unchecked, parse_usize_unchecked,
+    },
+    percent::{PercentEncodeSet, PushPercentDecodedUnchecked, PushPercentEncodedUnchecked},
+    reader::UncheckedReader,
+    utf16::{utf16_len, utf8_len_of_utf16, PushStrAsUtf16Unchecked, PushUtf16Unchecked},
+    writer::UncheckedWriter,
+};
 use self::{generic_string::GenericString, generic_vec::GenericVec};
+#[cfg(feature = "std")]
+pub use self::{io::UncheckedIoWriter, os::PushComponentUnchecked};
 use alloc::vec::Vec;
 
+mod ascii;
+mod ascii_buf;
+mod base64;
+mod binary;
+mod case;
+mod escape;
+mod fill;
+mod float;
 mod generic_string;
 mod generic_vec;
+mod hex;
+mod int;
+#[cfg(feature = "std")]
+mod io;
+mod latin1;
+mod leb128;
+#[cfg(feature = "std")]
+mod os;
+mod parse;
+mod percent;
+mod reader;
+mod utf16;
+mod writer;
 
 /// A trait for `push` without the capacity check.
 pub trait PushUnchecked<T> {
@@ -48,32 +98,42 @@ impl<S: GenericString> PushUnchecked<char> for S {
         let len = self.len();
         let ch_len = ch.len_utf8();
         debug_assert!(len + ch_len <= self.capacity());
-        let ptr = self.as_mut_vec().as_mut_ptr().add(len);
-        match ch_len {
-            1 => {
-                *ptr = ch as u8;
-            }
-            2 => {
-                *ptr = (ch as u32 >> 6 & 0x1F) as u8 | 0b1100_0000;
-                *ptr.add(1) = (ch as u32 & 0x3F) as u8 | 0b1000_0000;
-            }
-            3 => {
-                *ptr = (ch as u32 >> 12 & 0x0F) as u8 | 0b1110_0000;
-                *ptr.add(1) = (ch as u32 >> 6 & 0x3F) as u8 | 0b1000_0000;
-                *ptr.add(2) = (ch as u32 & 0x3F) as u8 | 0b1000_0000;
-            }
-            4 => {
-                *ptr = (ch as u32 >> 18 & 0x07) as u8 | 0b1111_0000;
-                *ptr.add(1) = (ch as u32 >> 12 & 0x3F) as u8 | 0b1000_0000;
-                *ptr.add(2) = (ch as u32 >> 6 & 0x3F) as u8 | 0b1000_0000;
-                *ptr.add(3) = (ch as u32 & 0x3F) as u8 | 0b1000_0000;
-            }
-            _ => core::hint::unreachable_unchecked(),
-        }
+        write_utf8(ch, ch_len, self.as_mut_vec().as_mut_ptr().add(len));
         self.as_mut_vec().set_len(len + ch_len);
     }
 }
 
+/// Writes the `ch_len` bytes of the UTF-8 encoding of `ch` to `ptr`.
+///
+/// # Safety
+///
+/// `ch_len` must be `ch.len_utf8()` and `ptr` must be valid for `ch_len`
+/// bytes.
+#[inline]
+unsafe fn write_utf8(ch: char, ch_len: usize, ptr: *mut u8) {
+    match ch_len {
+        1 => {
+            *ptr = ch as u8;
+        }
+        2 => {
+            *ptr = (ch as u32 >> 6 & 0x1F) as u8 | 0b1100_0000;
+            *ptr.add(1) = (ch as u32 & 0x3F) as u8 | 0b1000_0000;
+        }
+        3 => {
+            *ptr = (ch as u32 >> 12 & 0x0F) as u8 | 0b1110_0000;
+            *ptr.add(1) = (ch as u32 >> 6 & 0x3F) as u8 | 0b1000_0000;
+            *ptr.add(2) = (ch as u32 & 0x3F) as u8 | 0b1000_0000;
+        }
+        4 => {
+            *ptr = (ch as u32 >> 18 & 0x07) as u8 | 0b1111_0000;
+            *ptr.add(1) = (ch as u32 >> 12 & 0x3F) as u8 | 0b1000_0000;
+            *ptr.add(2) = (ch as u32 >> 6 & 0x3F) as u8 | 0b1000_0000;
+            *ptr.add(3) = (ch as u32 & 0x3F) as u8 | 0b1000_0000;
+        }
+        _ => core::hint::unreachable_unchecked(),
+    }
+}
+
 /// A trait for `extend` without the capacity check.
 pub trait ExtendUnchecked<T> {
     /// Extends a collection with the contents of an iterator without the
@@ -170,6 +230,30 @@ pub trait ExtendFromWithinUnchecked {
         R: core::ops::RangeBounds<usize>;
 }
 
+/// Converts `src` into a `start..end` pair for a collection of length `len`,
+/// checking its validity only in debug builds.
+#[inline]
+fn range_unchecked<R: core::ops::RangeBounds<usize>>(src: &R, len: usize) -> (usize, usize) {
+    let start = match src.start_bound() {
+        core::ops::Bound::Included(&start) => start,
+        core::ops::Bound::Excluded(&start) => {
+            debug_assert!(start != usize::MAX);
+            start + 1
+        }
+        core::ops::Bound::Unbounded => 0,
+    };
+    let end = match src.end_bound() {
+        core::ops::Bound::Included(&end) => {
+            debug_assert!(end != usize::MAX);
+            end + 1
+        }
+        core::ops::Bound::Excluded(&end) => end,
+        core::ops::Bound::Unbounded => len,
+    };
+    debug_assert!(start <= end && end <= len);
+    (start, end)
+}
+
 impl<T: Copy, V: GenericVec<Item = T>> ExtendFromWithinUnchecked for V {
     /// `extend_from_within` without the capacity check
     /// and the bounds check for the range.
@@ -183,23 +267,7 @@ impl<T: Copy, V: GenericVec<Item = T>> ExtendFromWithinUnchecked for V {
     where
         R: core::ops::RangeBounds<usize>,
     {
-        let start = match src.start_bound() {
-            core::ops::Bound::Included(&start) => start,
-            core::ops::Bound::Excluded(&start) => {
-                debug_assert!(start != usize::MAX);
-                start + 1
-            }
-            core::ops::Bound::Unbounded => 0,
-        };
-        let end = match src.end_bound() {
-            core::ops::Bound::Included(&end) => {
-                debug_assert!(end != usize::MAX);
-                end + 1
-            }
-            core::ops::Bound::Excluded(&end) => end,
-            core::ops::Bound::Unbounded => self.len(),
-        };
-        debug_assert!(start <= end && end <= self.len());
+        let (start, end) = range_unchecked(&src, self.len());
 
         let count = end - start;
         debug_assert!(self.capacity() - self.len() >= count);
@@ -215,6 +283,153 @@ impl<T: Copy, V: GenericVec<Item = T>> ExtendFromWithinUnchecked for V {
     }
 }
 
+/// `extend_from_within` for strings. A blanket implementation over
+/// [`GenericString`] would conflict with the one over [`GenericVec`].
+#[inline]
+unsafe fn string_extend_from_within_unchecked<S: GenericString, R>(s: &mut S, src: R)
+where
+    R: core::ops::RangeBounds<usize>,
+{
+    let (start, end) = range_unchecked(&src, s.len());
+    debug_assert!(s.as_str().is_char_boundary(start));
+    debug_assert!(s.as_str().is_char_boundary(end));
+    s.as_mut_vec().extend_from_within_unchecked(start..end);
+}
+
+impl ExtendFromWithinUnchecked for alloc::string::String {
+    /// `extend_from_within` without the capacity check
+    /// and the bounds check for the range.
+    ///
+    /// # Safety
+    ///
+    /// - `src` must be a valid index for `self`
+    /// - both ends of `src` must lie on `char` boundaries
+    /// - capacity of `self` must be sufficient for the new bytes
+    #[inline]
+    unsafe fn extend_from_within_unchecked<R>(&mut self, src: R)
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        string_extend_from_within_unchecked(self, src);
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> ExtendFromWithinUnchecked for heapless::String<N> {
+    /// `extend_from_within` without the capacity check
+    /// and the bounds check for the range.
+    ///
+    /// # Safety
+    ///
+    /// - `src` must be a valid index for `self`
+    /// - both ends of `src` must lie on `char` boundaries
+    /// - capacity of `self` must be sufficient for the new bytes
+    #[inline]
+    unsafe fn extend_from_within_unchecked<R>(&mut self, src: R)
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        string_extend_from_within_unchecked(self, src);
+    }
+}
+
+/// A trait for `extend_from_within` of [`Clone`] items without the capacity
+/// and bounds checks.
+pub trait ExtendFromWithinCloneUnchecked {
+    /// Clones elements from `src` range to the end of the collection
+    /// without the capacity check and the bounds check for the range.
+    ///
+    /// # Safety
+    ///
+    /// `src` must be a valid index for the collection.
+    /// The capacity of the collection must be sufficient for the new items.
+    unsafe fn extend_from_within_clone_unchecked<R>(&mut self, src: R)
+    where
+        R: core::ops::RangeBounds<usize>;
+}
+
+impl<T: Clone, V: GenericVec<Item = T>> ExtendFromWithinCloneUnchecked for V {
+    /// `extend_from_within` without the capacity check
+    /// and the bounds check for the range.
+    ///
+    /// If a `clone` panics, the items cloned so far are kept.
+    ///
+    /// # Safety
+    ///
+    /// - `src` must be a valid index for `self`
+    /// - capacity of `self` must be sufficient for the new items
+    #[inline]
+    unsafe fn extend_from_within_clone_unchecked<R>(&mut self, src: R)
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        let (start, end) = range_unchecked(&src, self.len());
+        debug_assert!(self.capacity() - self.len() >= end - start);
+
+        for i in start..end {
+            let item = (*self.as_ptr().add(i)).clone();
+            core::ptr::write(self.as_mut_ptr().add(self.len()), item);
+            self.set_len(self.len() + 1);
+        }
+    }
+}
+
+/// A trait for LZ77-style back-reference copies without the capacity and
+/// bounds checks.
+pub trait ExtendFromBackReferenceUnchecked {
+    /// Appends `len` elements, copying them one by one from `distance`
+    /// elements back, without the capacity check and the bounds check for
+    /// the distance.
+    ///
+    /// Unlike [`ExtendFromWithinUnchecked`], the source may overlap with the
+    /// appended elements, in which case the last `distance` elements are
+    /// repeated.
+    ///
+    /// # Safety
+    ///
+    /// `distance` must be nonzero and not greater than the length of the
+    /// collection.
+    /// The capacity of the collection must be sufficient for the new items.
+    unsafe fn extend_from_back_reference_unchecked(&mut self, distance: usize, len: usize);
+}
+
+impl<T: Copy, V: GenericVec<Item = T>> ExtendFromBackReferenceUnchecked for V {
+    /// Appends `len` elements, copying them one by one from `distance`
+    /// elements back, without the capacity check and the bounds check for
+    /// the distance.
+    ///
+    /// # Safety
+    ///
+    /// - `distance` must be in `1..=self.len()`
+    /// - `len` must be `<= self.capacity() - self.len()`
+    #[inline]
+    unsafe fn extend_from_back_reference_unchecked(&mut self, distance: usize, len: usize) {
+        debug_assert!(distance != 0 && distance <= self.len());
+        debug_assert!(len <= self.capacity() - self.len());
+
+        let dst = self.as_mut_ptr().add(self.len());
+        let src = dst.sub(distance);
+        if distance == 1 {
+            let value = *src;
+            for i in 0..len {
+                core::ptr::write(dst.add(i), value);
+            }
+        } else if distance >= len {
+            core::ptr::copy_nonoverlapping(src, dst, len);
+        } else {
+            // `[src, dst + copied)` has period `distance`, so it can be
+            // reused as the source as long as `copied` is a multiple of it
+            let mut copied = 0;
+            while copied < len {
+                let count = (copied + distance).min(len - copied);
+                core::ptr::copy_nonoverlapping(src, dst.add(copied), count);
+                copied += count;
+            }
+        }
+        self.set_len(self.len() + len);
+    }
+}
+
 /// A trait for `push_str` without the capacity check.
 pub trait PushStrUnchecked {
     /// Appends a given string slice onto the end of this collection without
@@ -234,9 +449,171 @@ impl<S: GenericString> PushStrUnchecked for S {
     /// `self.len() + string.len()` must be `<= self.capacity()`.
     #[inline]
     unsafe fn push_str_unchecked(&mut self, string: &str) {
-        self.as_mut_vec()
-            .extend_from_slice_unchecked(string.as_bytes());
+        extend_bytes_unchecked(self.as_mut_vec(), string.as_bytes());
+    }
+}
+
+/// Appends `bytes` to `v` with [`copy_bytes`] without the capacity check.
+#[inline]
+unsafe fn extend_bytes_unchecked<V: GenericVec<Item = u8>>(v: &mut V, bytes: &[u8]) {
+    debug_assert!(bytes.len() <= v.capacity() - v.len());
+    copy_bytes(bytes.as_ptr(), v.as_mut_ptr().add(v.len()), bytes.len());
+    v.set_len(v.len() + bytes.len());
+}
+
+/// Copies `len` bytes from `src` to `dst`, which must not overlap.
+///
+/// Up to 32 bytes are copied inline with two overlapping loads and stores of
+/// the largest power-of-two width that fits, avoiding a `memcpy` call for
+/// the short strings that are pushed most often.
+#[inline]
+const unsafe fn copy_bytes(src: *const u8, dst: *mut u8, len: usize) {
+    #[inline]
+    const unsafe fn copy_overlapping<T>(src: *const u8, dst: *mut u8, len: usize) {
+        let head = src.cast::<T>().read_unaligned();
+        let tail = src.add(len - size_of::<T>()).cast::<T>().read_unaligned();
+        dst.cast::<T>().write_unaligned(head);
+        dst.add(len - size_of::<T>())
+            .cast::<T>()
+            .write_unaligned(tail);
+    }
+
+    match len {
+        0 => {}
+        1 => *dst = *src,
+        2..4 => copy_overlapping::<u16>(src, dst, len),
+        4..8 => copy_overlapping::<u32>(src, dst, len),
+        8..16 => copy_overlapping::<u64>(src, dst, len),
+        16..=32 => copy_overlapping::<u128>(src, dst, len),
+        _ => core::ptr::copy_nonoverlapping(src, dst, len),
+    }
+}
+
+/// A trait for appending trusted UTF-8 bytes to a string without validation
+/// and the capacity check.
+pub trait PushUtf8Unchecked {
+    /// Appends `bytes`, which must be valid UTF-8, onto the end of this string
+    /// without UTF-8 validation and the capacity check.
+    ///
+    /// # Safety
+    ///
+    /// - `bytes` must be valid UTF-8
+    /// - the capacity of the string must be sufficient for the new bytes
+    unsafe fn push_utf8_unchecked(&mut self, bytes: &[u8]);
+}
+
+impl<S: GenericString> PushUtf8Unchecked for S {
+    /// `push_str(core::str::from_utf8_unchecked(bytes))` without the
+    /// capacity check.
+    ///
+    /// # Safety
+    ///
+    /// - `bytes` must be valid UTF-8
+    /// - `self.len() + bytes.len()` must be `<= self.capacity()`
+    #[inline]
+    unsafe fn push_utf8_unchecked(&mut self, bytes: &[u8]) {
+        debug_assert!(core::str::from_utf8(bytes).is_ok());
+        extend_bytes_unchecked(self.as_mut_vec(), bytes);
+    }
+}
+
+/// A trait for `join` into an existing collection without the capacity check.
+pub trait PushJoinedUnchecked<P, Sep: ?Sized> {
+    /// Appends `parts` separated by `sep` onto the end of this collection
+    /// without the capacity check.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the collection must be sufficient for the joined items.
+    unsafe fn push_joined_unchecked(&mut self, parts: &[P], sep: &Sep);
+}
+
+/// Copies `parts` separated by `sep` into the spare capacity of `v`.
+#[inline]
+unsafe fn push_joined_raw<'a, T: Copy + 'a, V: GenericVec<Item = T>>(
+    v: &mut V,
+    parts: impl IntoIterator<Item = &'a [T]>,
+    sep: &[T],
+) {
+    let mut dst = v.as_mut_ptr().add(v.len());
+    let mut len = 0;
+    let mut parts = parts.into_iter();
+    if let Some(first) = parts.next() {
+        core::ptr::copy_nonoverlapping(first.as_ptr(), dst, first.len());
+        dst = dst.add(first.len());
+        len += first.len();
+        for part in parts {
+            core::ptr::copy_nonoverlapping(sep.as_ptr(), dst, sep.len());
+            dst = dst.add(sep.len());
+            core::ptr::copy_nonoverlapping(part.as_ptr(), dst, part.len());
+            dst = dst.add(part.len());
+            len += sep.len() + part.len();
+        }
+    }
+    v.set_len(v.len() + len);
+}
+
+impl<S: GenericString, P: AsRef<str>> PushJoinedUnchecked<P, str> for S {
+    /// `push_str(&parts.join(sep))` without the allocation and the capacity
+    /// check.
+    ///
+    /// # Safety
+    ///
+    /// `self.len() + joined_len(parts, sep.as_bytes())` must be
+    /// `<= self.capacity()`.
+    #[inline]
+    unsafe fn push_joined_unchecked(&mut self, parts: &[P], sep: &str) {
+        debug_assert!(
+            joined_len_raw(parts.iter().map(|part| part.as_ref().len()), sep.len())
+                <= self.capacity() - self.len()
+        );
+        push_joined_raw(
+            self.as_mut_vec(),
+            parts.iter().map(|part| part.as_ref().as_bytes()),
+            sep.as_bytes(),
+        );
+    }
+}
+
+impl<T: Copy, V: GenericVec<Item = T>, P: AsRef<[T]>> PushJoinedUnchecked<P, [T]> for V {
+    /// `extend_from_slice(&parts.join(sep))` without the allocation and the
+    /// capacity check.
+    ///
+    /// # Safety
+    ///
+    /// `joined_len(parts, sep)` must be `<= self.capacity() - self.len()`.
+    #[inline]
+    unsafe fn push_joined_unchecked(&mut self, parts: &[P], sep: &[T]) {
+        debug_assert!(joined_len(parts, sep) <= self.capacity() - self.len());
+        push_joined_raw(self, parts.iter().map(AsRef::as_ref), sep);
+    }
+}
+
+/// Returns the exact number of items that [`PushJoinedUnchecked`] appends for
+/// the given `parts` and `sep`.
+///
+/// For strings, pass `sep.as_bytes()` to get the length in bytes.
+///
+/// # Panics
+///
+/// Panics if the length overflows `usize`.
+#[inline]
+#[must_use]
+pub fn joined_len<T, P: AsRef<[T]>>(parts: &[P], sep: &[T]) -> usize {
+    joined_len_raw(parts.iter().map(|part| part.as_ref().len()), sep.len())
+}
+
+#[inline]
+fn joined_len_raw(part_lens: impl ExactSizeIterator<Item = usize>, sep_len: usize) -> usize {
+    let mut len = sep_len
+        .checked_mul(part_lens.len().saturating_sub(1))
+        .expect("joined length overflows usize");
+    for part_len in part_lens {
+        len = len
+            .checked_add(part_len)
+            .expect("joined length overflows usize");
     }
+    len
 }
 
 /// A trait for `copy_from_slice` without the length check.
@@ -0,0 +1,60 @@
+use super::PushUnchecked;
+use std::{
+    ffi::{OsStr, OsString},
+    path::{self, Component, Path, PathBuf},
+};
+
+impl<'a> PushUnchecked<&'a OsStr> for OsString {
+    /// [`Self::push`] without the capacity check.
+    ///
+    /// # Safety
+    ///
+    /// `s.len()` must be `<= self.capacity() - self.len()`.
+    #[inline]
+    unsafe fn push_unchecked(&mut self, s: &'a OsStr) {
+        debug_assert!(s.len() <= self.capacity() - self.len());
+        if s.len() > self.capacity() - self.len() {
+            core::hint::unreachable_unchecked();
+        }
+        self.push(s);
+    }
+}
+
+/// A trait for `PathBuf::push` of a plain name without the capacity check and
+/// path parsing.
+pub trait PushComponentUnchecked {
+    /// Appends `component` after a separator, which is omitted if the path is
+    /// empty or already ends with one, without the capacity check.
+    ///
+    /// Unlike [`PathBuf::push`], `component` is not parsed, so it is never
+    /// treated as an absolute path replacing the current one.
+    /// On Windows, a path that is only a drive prefix such as `C:` also gets
+    /// a separator.
+    ///
+    /// # Safety
+    ///
+    /// - `component` must be a plain relative name, which is a single
+    ///   [`Component::Normal`] without separators
+    /// - the capacity of the path must be sufficient for the separator and
+    ///   `component`, which is guaranteed by `1 + component.len()` spare bytes
+    unsafe fn push_component_unchecked<C: AsRef<OsStr>>(&mut self, component: C);
+}
+
+impl PushComponentUnchecked for PathBuf {
+    #[inline]
+    unsafe fn push_component_unchecked<C: AsRef<OsStr>>(&mut self, component: C) {
+        let component = component.as_ref();
+        debug_assert!(Path::new(component)
+            .components()
+            .eq([Component::Normal(component)]));
+        let s = self.as_mut_os_string();
+        let needs_separator = s
+            .as_encoded_bytes()
+            .last()
+            .is_some_and(|&byte| !path::is_separator(char::from(byte)));
+        if needs_separator {
+            s.push_unchecked(OsStr::new(path::MAIN_SEPARATOR_STR));
+        }
+        s.push_unchecked(component);
+    }
+}
@@ -0,0 +1,113 @@
+/// Parses exactly 8 ASCII digits loaded as a little-endian `u64`.
+#[inline]
+const fn parse_8_digits(chunk: u64) -> u64 {
+    const MASK: u64 = 0x0000_00FF_0000_00FF;
+    const MUL1: u64 = 100 + (1_000_000 << 32);
+    const MUL2: u64 = 1 + (10_000 << 32);
+    let chunk = chunk.wrapping_sub(0x3030_3030_3030_3030);
+    let chunk = chunk.wrapping_mul(10).wrapping_add(chunk >> 8);
+    (chunk & MASK)
+        .wrapping_mul(MUL1)
+        .wrapping_add(((chunk >> 16) & MASK).wrapping_mul(MUL2))
+        >> 32
+}
+
+/// Parses ASCII digits whose value fits in a `u64`.
+#[inline]
+unsafe fn parse_digits_u64(mut digits: &[u8]) -> u64 {
+    let mut n: u64 = 0;
+    while digits.len() >= 8 {
+        let chunk = u64::from_le_bytes(digits.as_ptr().cast::<[u8; 8]>().read_unaligned());
+        n = n
+            .wrapping_mul(100_000_000)
+            .wrapping_add(parse_8_digits(chunk));
+        digits = digits.get_unchecked(8..);
+    }
+    for &digit in digits {
+        n = n.wrapping_mul(10).wrapping_add(u64::from(digit - b'0'));
+    }
+    n
+}
+
+/// Parses ASCII digits whose value fits in a `u128`.
+#[inline]
+unsafe fn parse_digits_u128(digits: &[u8]) -> u128 {
+    if digits.len() <= 19 {
+        return u128::from(parse_digits_u64(digits));
+    }
+    let (head, mut rest) = digits.split_at_unchecked(digits.len() % 16);
+    let mut n = u128::from(parse_digits_u64(head));
+    while !rest.is_empty() {
+        let (chunk, tail) = rest.split_at_unchecked(16);
+        n = n
+            .wrapping_mul(10_u128.pow(16))
+            .wrapping_add(u128::from(parse_digits_u64(chunk)));
+        rest = tail;
+    }
+    n
+}
+
+macro_rules! parse_unsigned_unchecked {
+    ($($name:ident: $t:ty => $parse:ident;)*) => ($(
+        #[doc = concat!("Parses ASCII decimal digits as a `", stringify!($t), "` without validation.")]
+        ///
+        /// Leading zeros are allowed and no sign is accepted.
+        ///
+        /// # Safety
+        ///
+        /// `bytes` must be non-empty, consist only of ASCII digits, and its
+        #[doc = concat!("value must fit in `", stringify!($t), "`.")]
+        #[inline]
+        #[must_use]
+        #[allow(clippy::cast_possible_truncation)]
+        pub unsafe fn $name(bytes: &[u8]) -> $t {
+            debug_assert!(
+                bytes.iter().all(u8::is_ascii_digit)
+                    && core::str::from_utf8(bytes).is_ok_and(|s| s.parse::<$t>().is_ok())
+            );
+            $parse(bytes) as $t
+        }
+    )*)
+}
+
+macro_rules! parse_signed_unchecked {
+    ($($name:ident: $t:ty => $parse:ident;)*) => ($(
+        #[doc = concat!("Parses an optionally signed ASCII decimal integer as an `", stringify!($t), "` without validation.")]
+        ///
+        /// The sign may be `+` or `-`, and leading zeros are allowed after it.
+        ///
+        /// # Safety
+        ///
+        /// `bytes` must consist of an optional sign followed by at least one
+        #[doc = concat!("ASCII digit, and its value must fit in `", stringify!($t), "`.")]
+        #[inline]
+        #[must_use]
+        #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+        pub unsafe fn $name(bytes: &[u8]) -> $t {
+            debug_assert!(core::str::from_utf8(bytes).is_ok_and(|s| s.parse::<$t>().is_ok()));
+            match bytes.first() {
+                Some(b'-') => ($parse(bytes.get_unchecked(1..)) as $t).wrapping_neg(),
+                Some(b'+') => $parse(bytes.get_unchecked(1..)) as $t,
+                _ => $parse(bytes) as $t,
+            }
+        }
+    )*)
+}
+
+parse_unsigned_unchecked! {
+    parse_u8_unchecked: u8 => parse_digits_u64;
+    parse_u16_unchecked: u16 => parse_digits_u64;
+    parse_u32_unchecked: u32 => parse_digits_u64;
+    parse_u64_unchecked: u64 => parse_digits_u64;
+    parse_u128_unchecked: u128 => parse_digits_u128;
+    parse_usize_unchecked: usize => parse_digits_u64;
+}
+
+parse_signed_unchecked! {
+    parse_i8_unchecked: i8 => parse_digits_u64;
+    parse_i16_unchecked: i16 => parse_digits_u64;
+    parse_i32_unchecked: i32 => parse_digits_u64;
+    parse_i64_unchecked: i64 => parse_digits_u64;
+    parse_i128_unchecked: i128 => parse_digits_u128;
+    parse_isize_unchecked: isize => parse_digits_u64;
+}
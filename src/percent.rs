@@ -0,0 +1,238 @@
+use super::{
+    ascii_buf::AsciiBuf,
+    generic_string::GenericString,
+    generic_vec::GenericVec,
+    hex::{HEX_DECODE, HEX_UPPER},
+};
+
+/// A set of ASCII bytes to percent-encode. Non-ASCII bytes are always
+/// encoded.
+///
+/// The predefined sets follow the [URL Standard](https://url.spec.whatwg.org/#percent-encoded-bytes).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PercentEncodeSet {
+    mask: u128,
+}
+
+impl PercentEncodeSet {
+    /// The C0 control percent-encode set: C0 controls and DEL.
+    pub const CONTROLS: Self = Self {
+        mask: ((1 << 0x20) - 1) | (1 << 0x7F),
+    };
+    /// The fragment percent-encode set.
+    pub const FRAGMENT: Self = Self::CONTROLS
+        .add(b' ')
+        .add(b'"')
+        .add(b'<')
+        .add(b'>')
+        .add(b'`');
+    /// The query percent-encode set.
+    pub const QUERY: Self = Self::CONTROLS
+        .add(b' ')
+        .add(b'"')
+        .add(b'#')
+        .add(b'<')
+        .add(b'>');
+    /// The path percent-encode set.
+    pub const PATH: Self = Self::QUERY
+        .add(b'?')
+        .add(b'^')
+        .add(b'`')
+        .add(b'{')
+        .add(b'}');
+    /// The userinfo percent-encode set.
+    pub const USERINFO: Self = Self::PATH
+        .add(b'/')
+        .add(b':')
+        .add(b';')
+        .add(b'=')
+        .add(b'@')
+        .add(b'[')
+        .add(b'\\')
+        .add(b']')
+        .add(b'|');
+    /// The component percent-encode set, which makes the output safe to use
+    /// in any part of a URL.
+    pub const COMPONENT: Self = Self::USERINFO
+        .add(b'$')
+        .add(b'%')
+        .add(b'&')
+        .add(b'+')
+        .add(b',');
+
+    /// Returns the set with `byte` added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `byte` is not ASCII.
+    #[inline]
+    #[must_use]
+    pub const fn add(self, byte: u8) -> Self {
+        assert!(byte.is_ascii());
+        Self {
+            mask: self.mask | 1 << byte,
+        }
+    }
+
+    /// Returns the set with `byte` removed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `byte` is not ASCII.
+    #[inline]
+    #[must_use]
+    pub const fn remove(self, byte: u8) -> Self {
+        assert!(byte.is_ascii());
+        Self {
+            mask: self.mask & !(1 << byte),
+        }
+    }
+
+    /// Returns whether `byte` is percent-encoded with this set.
+    #[inline]
+    #[must_use]
+    pub const fn contains(self, byte: u8) -> bool {
+        !byte.is_ascii() || self.mask & 1 << byte != 0
+    }
+}
+
+/// A trait for percent-encoding without the capacity check.
+pub trait PushPercentEncodedUnchecked {
+    /// Appends `string` with the bytes in `set` percent-encoded as `%XX`
+    /// without the capacity check.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the collection must be sufficient for the encoded
+    /// string, which is guaranteed by `3 * string.len()` spare bytes.
+    unsafe fn push_percent_encoded_unchecked(&mut self, string: &str, set: PercentEncodeSet);
+}
+
+impl<B: AsciiBuf> PushPercentEncodedUnchecked for B {
+    #[inline]
+    unsafe fn push_percent_encoded_unchecked(&mut self, string: &str, set: PercentEncodeSet) {
+        let v = self.as_mut_byte_vec();
+        let bytes = string.as_bytes();
+        debug_assert!(
+            bytes.len() + 2 * bytes.iter().filter(|&&byte| set.contains(byte)).count()
+                <= v.capacity() - v.len()
+        );
+        let start = v.as_mut_ptr().add(v.len());
+        let mut dst = start;
+        let mut run_start = 0;
+        for (i, &byte) in bytes.iter().enumerate() {
+            if !set.contains(byte) {
+                continue;
+            }
+            let run = bytes.get_unchecked(run_start..i);
+            core::ptr::copy_nonoverlapping(run.as_ptr(), dst, run.len());
+            dst = dst.add(run.len());
+            let [hi, lo] = HEX_UPPER[byte as usize];
+            dst.cast::<[u8; 3]>().write_unaligned([b'%', hi, lo]);
+            dst = dst.add(3);
+            run_start = i + 1;
+        }
+        let run = bytes.get_unchecked(run_start..);
+        core::ptr::copy_nonoverlapping(run.as_ptr(), dst, run.len());
+        dst = dst.add(run.len());
+
+        v.set_len(v.len() + dst.offset_from(start).cast_unsigned());
+    }
+}
+
+/// A trait for percent-decoding without the capacity check.
+pub trait PushPercentDecodedUnchecked {
+    /// Appends `string` with the `%XX` sequences decoded without the capacity
+    /// check.
+    ///
+    /// A `%` that isn't followed by two hex digits is kept as is.
+    ///
+    /// # Safety
+    ///
+    /// - the capacity of the collection must be sufficient for the decoded
+    ///   bytes, which is guaranteed by `string.len()` spare bytes
+    /// - for strings, the decoded bytes must be valid UTF-8
+    unsafe fn push_percent_decoded_unchecked(&mut self, string: &str);
+}
+
+impl<V: GenericVec<Item = u8>> PushPercentDecodedUnchecked for V {
+    #[inline]
+    unsafe fn push_percent_decoded_unchecked(&mut self, string: &str) {
+        let bytes = string.as_bytes();
+        debug_assert!(percent_decoded_len(bytes) <= self.capacity() - self.len());
+        let decoded_len = write_percent_decoded(bytes, self.as_mut_ptr().add(self.len()));
+        self.set_len(self.len() + decoded_len);
+    }
+}
+
+/// Writes the percent-decoded `bytes` to `dst` and returns the number of
+/// bytes written.
+#[inline]
+unsafe fn write_percent_decoded(bytes: &[u8], start: *mut u8) -> usize {
+    let mut dst = start;
+    let mut i = 0;
+    while i < bytes.len() {
+        let mut byte = *bytes.get_unchecked(i);
+        if byte == b'%'
+            && i + 2 < bytes.len()
+            && bytes.get_unchecked(i + 1).is_ascii_hexdigit()
+            && bytes.get_unchecked(i + 2).is_ascii_hexdigit()
+        {
+            byte = HEX_DECODE[*bytes.get_unchecked(i + 1) as usize] << 4
+                | HEX_DECODE[*bytes.get_unchecked(i + 2) as usize];
+            i += 2;
+        }
+        *dst = byte;
+        dst = dst.add(1);
+        i += 1;
+    }
+    dst.offset_from(start).cast_unsigned()
+}
+
+fn percent_decoded_len(bytes: &[u8]) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%'
+            && i + 2 < bytes.len()
+            && bytes[i + 1].is_ascii_hexdigit()
+            && bytes[i + 2].is_ascii_hexdigit()
+        {
+            i += 2;
+        }
+        len += 1;
+        i += 1;
+    }
+    len
+}
+
+/// Percent-decoding for strings, which also checks the decoded bytes are UTF-8
+/// in debug builds. `String` and `heapless::String` call this from their own
+/// impls because byte vectors already get `PushPercentDecodedUnchecked`
+/// through the `V: GenericVec<Item = u8>` impl, and coherence rejects a
+/// second blanket impl for `S: GenericString`.
+#[inline]
+unsafe fn string_push_percent_decoded_unchecked<S: GenericString>(s: &mut S, string: &str) {
+    let bytes = string.as_bytes();
+    let v = s.as_mut_vec();
+    debug_assert!(percent_decoded_len(bytes) <= v.capacity() - v.len());
+    let dst = v.as_mut_ptr().add(v.len());
+    let decoded_len = write_percent_decoded(bytes, dst);
+    debug_assert!(core::str::from_utf8(core::slice::from_raw_parts(dst, decoded_len)).is_ok());
+    v.set_len(v.len() + decoded_len);
+}
+
+impl PushPercentDecodedUnchecked for alloc::string::String {
+    #[inline]
+    unsafe fn push_percent_decoded_unchecked(&mut self, string: &str) {
+        string_push_percent_decoded_unchecked(self, string);
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> PushPercentDecodedUnchecked for heapless::String<N> {
+    #[inline]
+    unsafe fn push_percent_decoded_unchecked(&mut self, string: &str) {
+        string_push_percent_decoded_unchecked(self, string);
+    }
+}
@@ -0,0 +1,110 @@
+use super::leb128::Uleb128;
+
+/// A cursor over a byte slice whose reads skip the bounds checks.
+///
+/// Useful when the total length of the input has been validated upfront.
+#[derive(Clone, Debug)]
+pub struct UncheckedReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> UncheckedReader<'a> {
+    /// Creates a reader positioned at the start of `bytes`.
+    #[inline]
+    #[must_use]
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Returns the bytes that have not been read yet.
+    #[inline]
+    #[must_use]
+    pub const fn remaining(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns the number of bytes that have not been read yet.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns `true` if all bytes have been read.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Reads one byte without the bounds check.
+    ///
+    /// # Safety
+    ///
+    /// At least 1 byte must remain.
+    #[inline]
+    pub unsafe fn read_u8_unchecked(&mut self) -> u8 {
+        let [byte] = self.read_array_unchecked();
+        byte
+    }
+
+    /// Reads a little-endian `u32` without the bounds check.
+    ///
+    /// # Safety
+    ///
+    /// At least 4 bytes must remain.
+    #[inline]
+    pub unsafe fn read_u32_le_unchecked(&mut self) -> u32 {
+        u32::from_le_bytes(self.read_array_unchecked())
+    }
+
+    /// Reads the next `n` bytes without the bounds check.
+    ///
+    /// # Safety
+    ///
+    /// At least `n` bytes must remain.
+    #[inline]
+    pub unsafe fn read_bytes_unchecked(&mut self, n: usize) -> &'a [u8] {
+        debug_assert!(n <= self.bytes.len());
+        let (bytes, rest) = self.bytes.split_at_unchecked(n);
+        self.bytes = rest;
+        bytes
+    }
+
+    /// Reads the next `N` bytes into an array without the bounds check.
+    ///
+    /// # Safety
+    ///
+    /// At least `N` bytes must remain.
+    #[inline]
+    pub unsafe fn read_array_unchecked<const N: usize>(&mut self) -> [u8; N] {
+        self.read_bytes_unchecked(N)
+            .as_ptr()
+            .cast::<[u8; N]>()
+            .read()
+    }
+
+    /// Advances past the next `n` bytes without the bounds check.
+    ///
+    /// # Safety
+    ///
+    /// At least `n` bytes must remain.
+    #[inline]
+    pub unsafe fn skip_unchecked(&mut self, n: usize) {
+        self.read_bytes_unchecked(n);
+    }
+
+    /// Reads an unsigned LEB128 value, which is also the protobuf varint
+    /// encoding, without the bounds and overflow checks.
+    ///
+    /// # Safety
+    ///
+    /// The remaining bytes must start with a complete encoding of a value
+    /// that fits in `U`.
+    #[inline]
+    pub unsafe fn read_uleb128_unchecked<U: Uleb128>(&mut self) -> U {
+        let (value, len) = U::read_uleb128(self.bytes);
+        self.bytes = self.bytes.get_unchecked(len..);
+        value
+    }
+}
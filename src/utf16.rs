@@ -0,0 +1,229 @@
+use super::{
+    generic_string::GenericString, generic_vec::GenericVec, write_utf8, ExtendUnchecked,
+    PushUnchecked,
+};
+use alloc::vec::Vec;
+
+/// Writes `code`, a Unicode scalar value, as one or two UTF-16 code units
+/// and returns their number.
+#[inline]
+#[allow(clippy::cast_possible_truncation)]
+unsafe fn write_utf16(code: u32, dst: *mut u16) -> usize {
+    if code < 0x1_0000 {
+        *dst = code as u16;
+        1
+    } else {
+        let code = code - 0x1_0000;
+        *dst = 0xD800 | (code >> 10) as u16;
+        *dst.add(1) = 0xDC00 | (code & 0x3FF) as u16;
+        2
+    }
+}
+
+#[inline]
+unsafe fn push_char_as_utf16<V: GenericVec<Item = u16>>(v: &mut V, ch: char) {
+    let len = v.len();
+    debug_assert!(ch.len_utf16() <= v.capacity() - len);
+    let ch_len = write_utf16(ch as u32, v.as_mut_ptr().add(len));
+    v.set_len(len + ch_len);
+}
+
+impl PushUnchecked<char> for Vec<u16> {
+    /// Appends `ch` as one or two UTF-16 code units without the capacity
+    /// check.
+    ///
+    /// # Safety
+    ///
+    /// `self.len() + ch.len_utf16()` must be `<= self.capacity()`.
+    #[inline]
+    unsafe fn push_unchecked(&mut self, ch: char) {
+        push_char_as_utf16(self, ch);
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> PushUnchecked<char> for heapless::Vec<u16, N> {
+    /// Appends `ch` as one or two UTF-16 code units without the capacity
+    /// check.
+    ///
+    /// # Safety
+    ///
+    /// `self.len() + ch.len_utf16()` must be `<= self.capacity()`.
+    #[inline]
+    unsafe fn push_unchecked(&mut self, ch: char) {
+        push_char_as_utf16(self, ch);
+    }
+}
+
+impl ExtendUnchecked<char> for Vec<u16> {
+    /// [`Extend::extend`] with UTF-16 encoding and without the capacity check.
+    ///
+    /// # Safety
+    ///
+    /// The capacity must be sufficient for the sum of `len_utf16()` of the
+    /// chars.
+    #[inline]
+    unsafe fn extend_unchecked<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        for ch in iter {
+            push_char_as_utf16(self, ch);
+        }
+    }
+}
+
+impl<'a> ExtendUnchecked<&'a char> for Vec<u16> {
+    /// [`Extend::extend`] with UTF-16 encoding and without the capacity check.
+    ///
+    /// # Safety
+    ///
+    /// The capacity must be sufficient for the sum of `len_utf16()` of the
+    /// chars.
+    #[inline]
+    unsafe fn extend_unchecked<I: IntoIterator<Item = &'a char>>(&mut self, iter: I) {
+        for &ch in iter {
+            push_char_as_utf16(self, ch);
+        }
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> ExtendUnchecked<char> for heapless::Vec<u16, N> {
+    /// [`Extend::extend`] with UTF-16 encoding and without the capacity check.
+    ///
+    /// # Safety
+    ///
+    /// The capacity must be sufficient for the sum of `len_utf16()` of the
+    /// chars.
+    #[inline]
+    unsafe fn extend_unchecked<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        for ch in iter {
+            push_char_as_utf16(self, ch);
+        }
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<'a, const N: usize> ExtendUnchecked<&'a char> for heapless::Vec<u16, N> {
+    /// [`Extend::extend`] with UTF-16 encoding and without the capacity check.
+    ///
+    /// # Safety
+    ///
+    /// The capacity must be sufficient for the sum of `len_utf16()` of the
+    /// chars.
+    #[inline]
+    unsafe fn extend_unchecked<I: IntoIterator<Item = &'a char>>(&mut self, iter: I) {
+        for &ch in iter {
+            push_char_as_utf16(self, ch);
+        }
+    }
+}
+
+/// A trait for pushing a string slice as UTF-16 without the capacity check.
+pub trait PushStrAsUtf16Unchecked {
+    /// Appends `string` encoded as UTF-16, like
+    /// `extend(string.encode_utf16())`, without the capacity check.
+    ///
+    /// # Safety
+    ///
+    /// The capacity of the collection must be sufficient for the new code
+    /// units, which is exactly [`utf16_len(string)`](utf16_len).
+    unsafe fn push_str_as_utf16_unchecked(&mut self, string: &str);
+}
+
+impl<V: GenericVec<Item = u16>> PushStrAsUtf16Unchecked for V {
+    #[inline]
+    unsafe fn push_str_as_utf16_unchecked(&mut self, string: &str) {
+        debug_assert!(utf16_len(string) <= self.capacity() - self.len());
+        let bytes = string.as_bytes();
+        let start = self.as_mut_ptr().add(self.len());
+        let mut dst = start;
+        let mut i = 0;
+        while i < bytes.len() {
+            let byte = u32::from(*bytes.get_unchecked(i));
+            let cont = |offset: usize| u32::from(*bytes.get_unchecked(i + offset) & 0x3F);
+            let (code, len) = if byte < 0x80 {
+                (byte, 1)
+            } else if byte < 0xE0 {
+                ((byte & 0x1F) << 6 | cont(1), 2)
+            } else if byte < 0xF0 {
+                ((byte & 0x0F) << 12 | cont(1) << 6 | cont(2), 3)
+            } else {
+                (
+                    (byte & 0x07) << 18 | cont(1) << 12 | cont(2) << 6 | cont(3),
+                    4,
+                )
+            };
+            dst = dst.add(write_utf16(code, dst));
+            i += len;
+        }
+        self.set_len(self.len() + dst.offset_from(start).cast_unsigned());
+    }
+}
+
+/// Returns the exact number of UTF-16 code units in `string`, which is
+/// `string.encode_utf16().count()`.
+#[inline]
+#[must_use]
+pub fn utf16_len(string: &str) -> usize {
+    string
+        .bytes()
+        .map(|byte| usize::from(!matches!(byte, 0x80..=0xBF)) + usize::from(byte >= 0xF0))
+        .sum()
+}
+
+/// A trait for pushing UTF-16 into a string without validation and the
+/// capacity check.
+pub trait PushUtf16Unchecked {
+    /// Appends the UTF-16 code units `utf16` transcoded to UTF-8, like
+    /// `extend(char::decode_utf16(utf16))` for well-formed input, without
+    /// validation and the capacity check.
+    ///
+    /// # Safety
+    ///
+    /// - `utf16` must be well-formed UTF-16, without unpaired surrogates
+    /// - the capacity of the string must be sufficient for the new bytes,
+    ///   which is exactly [`utf8_len_of_utf16(utf16)`](utf8_len_of_utf16)
+    unsafe fn push_utf16_unchecked(&mut self, utf16: &[u16]);
+}
+
+impl<S: GenericString> PushUtf16Unchecked for S {
+    #[inline]
+    unsafe fn push_utf16_unchecked(&mut self, utf16: &[u16]) {
+        debug_assert!(char::decode_utf16(utf16.iter().copied()).all(|ch| ch.is_ok()));
+        debug_assert!(utf8_len_of_utf16(utf16) <= self.capacity() - self.len());
+        let v = self.as_mut_vec();
+        let start = v.as_mut_ptr().add(v.len());
+        let mut dst = start;
+        let mut i = 0;
+        while i < utf16.len() {
+            let unit = u32::from(*utf16.get_unchecked(i));
+            let code = if (0xD800..0xDC00).contains(&unit) {
+                let low = u32::from(*utf16.get_unchecked(i + 1));
+                i += 2;
+                0x1_0000 + ((unit - 0xD800) << 10 | (low - 0xDC00))
+            } else {
+                i += 1;
+                unit
+            };
+            let ch = char::from_u32_unchecked(code);
+            let ch_len = ch.len_utf8();
+            write_utf8(ch, ch_len, dst);
+            dst = dst.add(ch_len);
+        }
+        v.set_len(v.len() + dst.offset_from(start).cast_unsigned());
+    }
+}
+
+/// Returns the exact number of UTF-8 bytes that well-formed UTF-16 `utf16`
+/// transcodes to.
+#[inline]
+#[must_use]
+pub fn utf8_len_of_utf16(utf16: &[u16]) -> usize {
+    utf16
+        .iter()
+        .map(|&unit| match unit {
+            0..0x80 => 1,
+            0x80..0x800 | 0xD800..0xE000 => 2,
+            _ => 3,
+        })
+        .sum()
+}
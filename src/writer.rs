@@ -0,0 +1,52 @@
+use super::{generic_string::GenericString, PushStrUnchecked, PushUnchecked};
+use core::fmt;
+
+/// A [`fmt::Write`] adapter that appends to a string without the capacity
+/// checks, so that `write!` into a pre-sized buffer skips them all.
+///
+/// The byte budget promised on construction is enforced in debug builds.
+#[derive(Debug)]
+pub struct UncheckedWriter<'a, S: GenericString> {
+    string: &'a mut S,
+    end: usize,
+}
+
+impl<'a, S: GenericString> UncheckedWriter<'a, S> {
+    /// Creates a writer that appends to `string`.
+    ///
+    /// # Safety
+    ///
+    /// - `budget` must be `<= string.capacity() - string.len()`
+    /// - at most `budget` bytes must be written through the writer
+    #[inline]
+    pub unsafe fn new(string: &'a mut S, budget: usize) -> Self {
+        debug_assert!(budget <= string.capacity() - string.len());
+        let end = string.len() + budget;
+        Self { string, end }
+    }
+
+    /// Returns the number of bytes that can still be written.
+    #[inline]
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.end - self.string.len()
+    }
+}
+
+impl<S: GenericString> fmt::Write for UncheckedWriter<'_, S> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        debug_assert!(s.len() <= self.remaining());
+        // SAFETY: the budget promised in `new` covers the write
+        unsafe { self.string.push_str_unchecked(s) };
+        Ok(())
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        debug_assert!(c.len_utf8() <= self.remaining());
+        // SAFETY: the budget promised in `new` covers the write
+        unsafe { self.string.push_unchecked(c) };
+        Ok(())
+    }
+}
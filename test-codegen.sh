@@ -1,4 +1,4 @@
 #!/bin/sh
 
-RUSTFLAGS="--emit=llvm-ir" cargo test --release --no-run --test codegen
+RUSTFLAGS="--emit=llvm-ir" cargo test --release --no-run --features std --test codegen
 FileCheck --input-file target/release/deps/codegen-*.ll tests/codegen.rs
@@ -1,6 +1,13 @@
-#![allow(clippy::no_mangle_with_rust_abi)]
+#![allow(clippy::no_mangle_with_rust_abi, clippy::missing_safety_doc)]
 
-use unchecked_std::ExtendFromSliceUnchecked;
+#[cfg(feature = "std")]
+use std::{
+    ffi::{OsStr, OsString},
+    path::PathBuf,
+};
+use unchecked_std::{ExtendFromSliceUnchecked, PushBinaryUnchecked};
+#[cfg(feature = "std")]
+use unchecked_std::{PushComponentUnchecked, PushUnchecked};
 
 const SLICE_LEN: usize = 10;
 const N_EXTENDS: usize = 16;
@@ -26,3 +33,64 @@ pub fn test_extend_from_slice_unchecked(xs: &[u8; SLICE_LEN]) -> Vec<u8> {
     }
     res
 }
+
+// CHECK-LABEL: @test_os_string_push_unchecked
+#[cfg(feature = "std")]
+#[no_mangle]
+pub unsafe fn test_os_string_push_unchecked(s: &mut OsString, x: &OsStr) {
+    // CHECK-NOT: do_reserve_and_handle
+    // CHECK: ret void
+    s.push_unchecked(x);
+}
+
+// CHECK-LABEL: @test_path_buf_push_component_unchecked
+#[cfg(feature = "std")]
+#[no_mangle]
+pub unsafe fn test_path_buf_push_component_unchecked(path: &mut PathBuf, component: &OsStr) {
+    // CHECK-NOT: do_reserve_and_handle
+    // CHECK: ret void
+    path.push_component_unchecked(component);
+}
+
+// CHECK-LABEL: @test_push_f64_le_unchecked
+#[no_mangle]
+pub unsafe fn test_push_f64_le_unchecked(v: &mut Vec<u8>, x: f64) {
+    // CHECK-NOT: do_reserve_and_handle
+    // CHECK: store {{double|i64}} %{{.*}}, ptr %{{.*}}, align 1
+    // CHECK-NOT: store i8
+    // CHECK: ret void
+    v.push_f64_le_unchecked(x);
+}
+
+// CHECK-LABEL: @test_push_u16_le_unchecked_many
+#[no_mangle]
+pub unsafe fn test_push_u16_le_unchecked_many(v: &mut Vec<u8>, x: u16, y: u16) {
+    // CHECK-NOT: do_reserve_and_handle
+    // CHECK: store i16 %x, ptr %{{.*}}, align 1
+    // CHECK: store i16 %y, ptr %{{.*}}, align 1
+    // CHECK-NOT: store i8
+    // CHECK: ret void
+    v.push_u16_le_unchecked(x);
+    v.push_u16_le_unchecked(y);
+}
+
+// CHECK-LABEL: @test_push_u32_le_unchecked
+#[no_mangle]
+pub unsafe fn test_push_u32_le_unchecked(v: &mut Vec<u8>, x: u32) {
+    // CHECK-NOT: do_reserve_and_handle
+    // CHECK: store i32 %x, ptr %{{.*}}, align 1
+    // CHECK-NOT: store i8
+    // CHECK: ret void
+    v.push_u32_le_unchecked(x);
+}
+
+// CHECK-LABEL: @test_push_u64_be_unchecked
+#[no_mangle]
+pub unsafe fn test_push_u64_be_unchecked(v: &mut Vec<u8>, x: u64) {
+    // CHECK-NOT: do_reserve_and_handle
+    // CHECK: @llvm.bswap.i64
+    // CHECK: store i64 %{{.*}}, ptr %{{.*}}, align 1
+    // CHECK-NOT: store i8
+    // CHECK: ret void
+    v.push_u64_be_unchecked(x);
+}
@@ -1,9 +1,11 @@
 #![no_std]
 
 extern crate alloc;
-use alloc::{string::String, vec, vec::Vec};
+extern crate std;
+use alloc::{borrow::ToOwned, format, string::String, vec, vec::Vec};
 use core::{
     array,
+    fmt::Write,
     ops::{Bound, RangeBounds},
 };
 use rand::{distributions::Alphanumeric, prelude::*};
@@ -47,6 +49,14 @@ fn test_string_push_unchecked() {
             assert_eq!(s, s_heapless.as_str());
         }
     }
+
+    // Pushes that exactly fill the capacity
+    let mut buf = [0; 4];
+    for ch in (0..=0x10_FFFF).step_by(7).filter_map(char::from_u32) {
+        let mut s_unchecked = String::with_capacity(ch.len_utf8());
+        unsafe { s_unchecked.push_unchecked(ch) };
+        assert_eq!(s_unchecked, &*ch.encode_utf8(&mut buf));
+    }
 }
 
 #[test]
@@ -115,16 +125,17 @@ fn test_vec_extend_from_within_unchecked() {
         v_unchecked.extend(INIT);
 
         v.extend_from_within(src.clone());
-        v_unchecked.extend_from_within_unchecked(src.clone());
-        assert_eq!(v, v_unchecked);
 
         #[cfg(feature = "heapless")]
         {
             let mut v_heapless = heapless::Vec::<_, { 2 * INIT.len() }>::new();
             v_heapless.extend(INIT);
-            v_heapless.extend_from_within_unchecked(src);
+            v_heapless.extend_from_within_unchecked(src.clone());
             assert_eq!(v, v_heapless.as_slice());
         }
+
+        v_unchecked.extend_from_within_unchecked(src);
+        assert_eq!(v, v_unchecked);
     }
 
     unsafe fn test_range_zero_sized(len: usize, src: impl RangeBounds<usize> + Clone) {
@@ -134,17 +145,17 @@ fn test_vec_extend_from_within_unchecked() {
 
         v.extend_from_within(src.clone());
 
-        v_unchecked.extend_from_within_unchecked(src.clone());
-
-        assert_eq!(v.len(), v_unchecked.len());
-
         #[cfg(feature = "heapless")]
         {
             let mut v_heapless = heapless::Vec::<(), { usize::MAX }>::new();
             v_heapless.set_len(len);
-            v_heapless.extend_from_within_unchecked(src);
+            v_heapless.extend_from_within_unchecked(src.clone());
             assert_eq!(v.len(), v_heapless.len());
         }
+
+        v_unchecked.extend_from_within_unchecked(src);
+
+        assert_eq!(v.len(), v_unchecked.len());
     }
 
     let mut v = vec![0, 1, 2];
@@ -193,6 +204,78 @@ fn test_vec_extend_from_within_unchecked() {
     }
 }
 
+#[test]
+fn test_string_extend_from_within_unchecked() {
+    let init = "aé€😀b";
+    let boundaries: Vec<usize> = init
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain([init.len()])
+        .collect();
+    for &start in &boundaries {
+        for &end in boundaries.iter().filter(|&&end| end >= start) {
+            let mut s = String::with_capacity(2 * init.len());
+            s.push_str(init);
+            let mut s_unchecked = String::with_capacity(2 * init.len());
+            s_unchecked.push_str(init);
+            s.extend_from_within(start..end);
+            unsafe { s_unchecked.extend_from_within_unchecked(start..end) };
+            assert_eq!(s, s_unchecked);
+            #[cfg(feature = "heapless")]
+            {
+                let mut s_heapless = heapless::String::<32>::new();
+                unsafe { s_heapless.push_str_unchecked(init) };
+                unsafe { s_heapless.extend_from_within_unchecked(start..end) };
+                assert_eq!(s, s_heapless.as_str());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_vec_extend_from_within_clone_unchecked() {
+    let init: Vec<String> = ["a", "bc", "", "def"].map(String::from).into();
+    for start in 0..=init.len() {
+        for end in start..=init.len() {
+            let mut v = Vec::with_capacity(2 * init.len());
+            v.extend_from_slice(&init);
+            let mut v_unchecked = Vec::with_capacity(2 * init.len());
+            v_unchecked.extend_from_slice(&init);
+            v.extend_from_within(start..end);
+            unsafe { v_unchecked.extend_from_within_clone_unchecked(start..end) };
+            assert_eq!(v, v_unchecked);
+            #[cfg(feature = "heapless")]
+            {
+                let mut v_heapless = heapless::Vec::<_, 8>::new();
+                v_heapless.extend(init.iter().cloned());
+                unsafe { v_heapless.extend_from_within_clone_unchecked(start..end) };
+                assert_eq!(v, v_heapless.as_slice());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_vec_extend_from_within_clone_unchecked_panic() {
+    #[derive(Debug, PartialEq)]
+    struct PanicOnClone(u32);
+
+    impl Clone for PanicOnClone {
+        fn clone(&self) -> Self {
+            assert!(self.0 != 2, "clone of 2");
+            Self(self.0)
+        }
+    }
+
+    let mut v = Vec::with_capacity(8);
+    v.extend([0, 1, 2, 3].map(PanicOnClone));
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| unsafe {
+        v.extend_from_within_clone_unchecked(..);
+    }));
+    assert!(result.is_err());
+    assert_eq!(v, [0, 1, 2, 3, 0, 1].map(PanicOnClone));
+}
+
 #[test]
 fn test_string_push_str_unchecked() {
     const N_STRINGS_TO_PUSH: usize = 5;
@@ -200,6 +283,7 @@ fn test_string_push_str_unchecked() {
     let mut rng = Pcg64Mcg::new(0xcafe_f00d_d15e_a5e5);
     let mut s = String::with_capacity(N_STRINGS_TO_PUSH * 4 * N_CHARS);
     let mut s_unchecked = String::with_capacity(N_STRINGS_TO_PUSH * 4 * N_CHARS);
+    let mut s_utf8 = String::with_capacity(N_STRINGS_TO_PUSH * 4 * N_CHARS);
     #[cfg(feature = "heapless")]
     let mut s_heapless = heapless::String::<{ N_STRINGS_TO_PUSH * 4 * N_CHARS }>::new();
     for _ in 0..N_STRINGS_TO_PUSH {
@@ -216,12 +300,28 @@ fn test_string_push_str_unchecked() {
         s.push_str(&string_to_push);
         unsafe { s_unchecked.push_str_unchecked(&string_to_push) };
         assert_eq!(s, s_unchecked);
+        unsafe { s_utf8.push_utf8_unchecked(string_to_push.as_bytes()) };
+        assert_eq!(s, s_utf8);
         #[cfg(feature = "heapless")]
         {
             unsafe { s_heapless.push_str_unchecked(&string_to_push) };
             assert_eq!(s, s_heapless.as_str());
+            s_heapless.truncate(s_heapless.len() - string_to_push.len());
+            unsafe { s_heapless.push_utf8_unchecked(string_to_push.as_bytes()) };
+            assert_eq!(s, s_heapless.as_str());
         }
     }
+
+    // Every length around the inline copy widths
+    let source: String = ('a'..='z').chain('A'..='Z').collect();
+    for len in 0..=40 {
+        let mut s_unchecked = String::with_capacity(len + 1);
+        unsafe {
+            s_unchecked.push_str_unchecked("-");
+            s_unchecked.push_str_unchecked(&source[..len]);
+        }
+        assert_eq!(s_unchecked[1..], source[..len]);
+    }
 }
 
 #[test]
@@ -239,27 +339,1018 @@ fn test_copy_from_slice_unchecked() {
 }
 
 macro_rules! test_push_many_unchecked_generic {
+    ($($t:ty: $value:expr;)*) => ($(
+        let value: $t = $value;
+        // Prefixes move the fill start through every alignment within 32 bytes
+        for prefix in 1..=32 / core::mem::size_of::<$t>().max(1) {
+            for count in (0..40).chain([LEN]) {
+                let mut v = Vec::with_capacity(prefix + count);
+                let mut v_unchecked = Vec::with_capacity(prefix + count);
+                #[cfg(feature = "heapless")]
+                let mut v_heapless = heapless::Vec::<$t, { 32 + LEN }>::new();
+                for _ in 0..prefix + count {
+                    v.push(value);
+                }
+                v_unchecked.extend(core::iter::repeat_n(value, prefix));
+                unsafe { v_unchecked.push_many_unchecked(value, count) };
+                assert_eq!(v, v_unchecked);
+                #[cfg(feature = "heapless")]
+                {
+                    unsafe {
+                        v_heapless.push_many_unchecked(value, prefix);
+                        v_heapless.push_many_unchecked(value, count);
+                    }
+                    assert_eq!(v, v_heapless.as_slice());
+                }
+            }
+        }
+    )*)
+}
+
+#[test]
+fn test_push_many_unchecked() {
+    const LEN: usize = 100;
+    test_push_many_unchecked_generic! {
+        u8: 5; i8: -5; u16: 0x1234; i16: -2; u32: 0x1234_5678; i32: -3; f32: 1.5; char: 'é';
+        u64: 0x0123_4567_89AB_CDEF; i64: -4; f64: -2.5; usize: 7; isize: -7;
+        u128: u128::MAX / 3; i128: i128::MIN + 1;
+        [u8; 2]: [1, 2]; [u8; 3]: [1, 2, 3]; [u8; 4]: [1, 2, 3, 4];
+        [u8; 16]: *b"0123456789abcdef";
+    }
+}
+
+#[test]
+fn test_push_joined_unchecked() {
+    const N_PARTS: usize = 10;
+    const N_CHARS: usize = 20;
+    let mut rng = Pcg64Mcg::new(0xcafe_f00d_d15e_a5e5);
+    let parts: [String; N_PARTS] = array::from_fn(|_| {
+        (0..rng.gen_range(0..N_CHARS))
+            .map(|_| rng.sample(Alphanumeric) as char)
+            .collect()
+    });
+    for n_parts in 0..=N_PARTS {
+        let parts = &parts[..n_parts];
+        for sep in ["", ",", ", ", "→"] {
+            let joined = parts.join(sep);
+            assert_eq!(joined_len(parts, sep.as_bytes()), joined.len());
+
+            let mut s = String::from("prefix");
+            s.reserve_exact(joined.len());
+            unsafe { s.push_joined_unchecked(parts, sep) };
+            assert_eq!(s, "prefix".to_owned() + &joined);
+            #[cfg(feature = "heapless")]
+            {
+                let mut s_heapless = heapless::String::<{ N_PARTS * (N_CHARS + 3) }>::new();
+                unsafe { s_heapless.push_joined_unchecked(parts, sep) };
+                assert_eq!(joined, s_heapless.as_str());
+            }
+
+            let parts: Vec<&[u8]> = parts.iter().map(String::as_bytes).collect();
+            let mut v = Vec::with_capacity(joined.len());
+            unsafe { v.push_joined_unchecked(&parts, sep.as_bytes()) };
+            assert_eq!(v, joined.as_bytes());
+            #[cfg(feature = "heapless")]
+            {
+                let mut v_heapless = heapless::Vec::<u8, { N_PARTS * (N_CHARS + 3) }>::new();
+                unsafe { v_heapless.push_joined_unchecked(&parts, sep.as_bytes()) };
+                assert_eq!(joined.as_bytes(), v_heapless.as_slice());
+            }
+        }
+    }
+
+    let parts = [vec![1u32, 2], vec![], vec![3]];
+    let mut v = Vec::with_capacity(joined_len(&parts, &[0, 0]));
+    unsafe { v.push_joined_unchecked(&parts, &[0, 0]) };
+    assert_eq!(v, parts.join(&[0, 0][..]));
+
+    let parts = [vec![(); 2], vec![(); 1]];
+    let mut v = Vec::new();
+    unsafe { v.push_joined_unchecked(&parts, &[()]) };
+    assert_eq!(v.len(), joined_len(&parts, &[()]));
+}
+
+macro_rules! test_extend_from_back_reference_unchecked_generic {
+    ($($t:ty)*) => ($(
+        let mut rng = Pcg64Mcg::new(0xcafe_f00d_d15e_a5e5);
+        let init: [$t; INIT_LEN] = array::from_fn(|_| rng.gen());
+        for distance in 1..=INIT_LEN {
+            for len in 0..=MAX_LEN {
+                let mut v = Vec::with_capacity(INIT_LEN + MAX_LEN);
+                v.extend_from_slice(&init);
+                let mut v_unchecked = v.clone();
+                v_unchecked.reserve_exact(MAX_LEN);
+                for _ in 0..len {
+                    v.push(v[v.len() - distance]);
+                }
+                unsafe { v_unchecked.extend_from_back_reference_unchecked(distance, len) };
+                assert_eq!(v, v_unchecked);
+                #[cfg(feature = "heapless")]
+                {
+                    let mut v_heapless = heapless::Vec::<$t, { INIT_LEN + MAX_LEN }>::new();
+                    unsafe { v_heapless.extend_from_slice_unchecked(&init) };
+                    unsafe { v_heapless.extend_from_back_reference_unchecked(distance, len) };
+                    assert_eq!(v, v_heapless.as_slice());
+                }
+            }
+        }
+    )*)
+}
+
+#[test]
+fn test_extend_from_back_reference_unchecked() {
+    const INIT_LEN: usize = 20;
+    const MAX_LEN: usize = 50;
+    test_extend_from_back_reference_unchecked_generic! { u8 u32 }
+}
+
+macro_rules! test_push_int_unchecked_generic {
     ($($t:ty)*) => ($(
-        let mut v = Vec::with_capacity(LEN);
-        let mut v_unchecked = Vec::with_capacity(LEN);
+        assert_eq!(<$t>::MAX_DECIMAL_LEN, format!("{}", <$t>::MIN).len().max(format!("{}", <$t>::MAX).len()));
+        assert_eq!(<$t>::MAX_BINARY_LEN, format!("{:b}", <$t>::MAX).len().max(format!("{:b}", <$t>::MIN).len()));
+        assert_eq!(<$t>::MAX_OCTAL_LEN, format!("{:o}", <$t>::MAX).len().max(format!("{:o}", <$t>::MIN).len()));
+        assert_eq!(<$t>::MAX_HEX_LEN, format!("{:x}", <$t>::MAX).len().max(format!("{:x}", <$t>::MIN).len()));
+
+        let mut rng = Pcg64Mcg::new(0xcafe_f00d_d15e_a5e5);
+        let mut values = vec![0, 1, 9, <$t>::MIN, <$t>::MAX];
+        for _ in 0..N_VALUES {
+            values.push(rng.gen::<$t>() >> rng.gen_range(0..<$t>::BITS));
+        }
+        for value in values {
+            let width = rng.gen_range(0..50);
+            let mut s = String::with_capacity(<$t>::MAX_DECIMAL_LEN + 50);
+            let mut v = Vec::with_capacity(<$t>::MAX_DECIMAL_LEN + 50);
+            #[cfg(feature = "heapless")]
+            let mut s_heapless = heapless::String::<{ 4 * 128 + 3 * 50 }>::new();
+            unsafe {
+                s.push_int_unchecked(value);
+                s.push_int_padded_unchecked(value, width);
+                v.push_int_unchecked(value);
+                v.push_int_padded_unchecked(value, width);
+            }
+            let expected = format!("{value}{value:0width$}");
+            assert_eq!(s, expected);
+            assert_eq!(v, expected.as_bytes());
+            #[cfg(feature = "heapless")]
+            unsafe {
+                s_heapless.push_int_unchecked(value);
+                s_heapless.push_int_padded_unchecked(value, width);
+            }
+
+            let mut s = String::with_capacity(3 * 128 + 3 * 50);
+            unsafe {
+                s.push_int_bin_unchecked(value);
+                s.push_int_bin_padded_unchecked(value, width);
+                s.push_int_oct_unchecked(value);
+                s.push_int_oct_padded_unchecked(value, width);
+                s.push_int_hex_unchecked(value);
+                s.push_int_hex_padded_unchecked(value, width);
+            }
+            let expected_radix =
+                format!("{value:b}{value:0width$b}{value:o}{value:0width$o}{value:x}{value:0width$x}");
+            assert_eq!(s, expected_radix);
+            #[cfg(feature = "heapless")]
+            {
+                unsafe {
+                    s_heapless.push_int_bin_unchecked(value);
+                    s_heapless.push_int_bin_padded_unchecked(value, width);
+                    s_heapless.push_int_oct_unchecked(value);
+                    s_heapless.push_int_oct_padded_unchecked(value, width);
+                    s_heapless.push_int_hex_unchecked(value);
+                    s_heapless.push_int_hex_padded_unchecked(value, width);
+                }
+                assert_eq!(expected + &expected_radix, s_heapless.as_str());
+            }
+        }
+    )*)
+}
+
+#[test]
+fn test_push_int_unchecked() {
+    const N_VALUES: usize = 1000;
+    test_push_int_unchecked_generic! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
+}
+
+#[test]
+fn test_push_float_unchecked() {
+    const N_VALUES: usize = 100_000;
+    let mut rng = Pcg64Mcg::new(0xcafe_f00d_d15e_a5e5);
+    let mut s = String::with_capacity(F64_MAX_LEN);
+    let mut v = Vec::with_capacity(F64_MAX_LEN);
+    #[cfg(feature = "heapless")]
+    let mut s_heapless = heapless::String::<F64_MAX_LEN>::new();
+
+    let mut f32_values = vec![
+        0.0,
+        -0.0,
+        1.0,
+        0.1,
+        1e-4,
+        1e16,
+        9_999_999e9,
+        -1.234_567_9e-5,
+        f32::MIN,
+        f32::MAX,
+        f32::MIN_POSITIVE,
+        f32::EPSILON,
+        f32::from_bits(1),
+        f32::NAN,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+    ];
+    f32_values.extend((0..N_VALUES).map(|_| f32::from_bits(rng.gen())));
+    f32_values.extend((0..N_VALUES).map(|_| f32::from(rng.gen::<u16>()) / 100.0));
+    for value in f32_values {
+        let expected = format!("{value:?}");
+        assert!(expected.len() <= F32_MAX_LEN);
+        s.clear();
+        v.clear();
+        unsafe {
+            s.push_f32_unchecked(value);
+            v.push_f32_unchecked(value);
+        }
+        assert_eq!(s, expected);
+        assert_eq!(v, expected.as_bytes());
+        let mut s_exact = String::with_capacity(expected.len());
+        unsafe { s_exact.push_f32_unchecked(value) };
+        assert_eq!(s_exact, expected);
         #[cfg(feature = "heapless")]
-        let mut v_heapless = heapless::Vec::<_, LEN>::new();
-        let value: $t = 5;
-        for _ in 0..LEN {
-            v.push(value);
+        {
+            s_heapless.clear();
+            unsafe { s_heapless.push_f32_unchecked(value) };
+            assert_eq!(s_heapless.as_str(), expected);
         }
-        unsafe { v_unchecked.push_many_unchecked(value, LEN) };
-        assert_eq!(v, v_unchecked);
+    }
+
+    let mut f64_values = vec![
+        0.0,
+        -0.0,
+        1.0,
+        0.1,
+        1e-4,
+        1e16,
+        9_999_999_999_999_998.0,
+        -1.234_567_890_123_456_7e-5,
+        -2.225_073_858_507_201_4e-308,
+        f64::MIN,
+        f64::MAX,
+        f64::MIN_POSITIVE,
+        f64::EPSILON,
+        f64::from_bits(1),
+        f64::NAN,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ];
+    f64_values.extend((0..N_VALUES).map(|_| f64::from_bits(rng.gen())));
+    f64_values.extend((0..N_VALUES).map(|_| f64::from(rng.gen::<u32>()) / 1000.0));
+    for value in f64_values {
+        let expected = format!("{value:?}");
+        assert!(expected.len() <= F64_MAX_LEN);
+        s.clear();
+        v.clear();
+        unsafe {
+            s.push_f64_unchecked(value);
+            v.push_f64_unchecked(value);
+        }
+        assert_eq!(s, expected);
+        assert_eq!(v, expected.as_bytes());
+        let mut s_exact = String::with_capacity(expected.len());
+        unsafe { s_exact.push_f64_unchecked(value) };
+        assert_eq!(s_exact, expected);
         #[cfg(feature = "heapless")]
         {
-            unsafe { v_heapless.push_many_unchecked(value, LEN) };
-            assert_eq!(v, v_heapless.as_slice());
+            s_heapless.clear();
+            unsafe { s_heapless.push_f64_unchecked(value) };
+            assert_eq!(s_heapless.as_str(), expected);
+        }
+    }
+}
+
+#[test]
+fn test_push_hex_unchecked() {
+    const MAX_LEN: usize = 100;
+    let mut rng = Pcg64Mcg::new(0xcafe_f00d_d15e_a5e5);
+    for len in 0..MAX_LEN {
+        let bytes: Vec<u8> = (0..len).map(|_| rng.gen()).collect();
+        let mut lower = String::new();
+        let mut upper = String::new();
+        for b in &bytes {
+            write!(lower, "{b:02x}").unwrap();
+            write!(upper, "{b:02X}").unwrap();
+        }
+
+        let mut s = String::with_capacity(4 * len);
+        unsafe {
+            s.push_hex_unchecked(&bytes);
+            s.push_hex_upper_unchecked(&bytes);
+        }
+        assert_eq!(s, lower.clone() + &upper);
+        let mut v = Vec::with_capacity(2 * len);
+        unsafe { v.push_hex_unchecked(&bytes) };
+        assert_eq!(v, lower.as_bytes());
+        #[cfg(feature = "heapless")]
+        {
+            let mut s_heapless = heapless::String::<{ 4 * MAX_LEN }>::new();
+            unsafe {
+                s_heapless.push_hex_unchecked(&bytes);
+                s_heapless.push_hex_upper_unchecked(&bytes);
+            }
+            assert_eq!(s, s_heapless.as_str());
+        }
+
+        let mut decoded = Vec::with_capacity(2 * len);
+        unsafe {
+            decoded.push_hex_decoded_unchecked(&lower);
+            decoded.push_hex_decoded_unchecked(&upper);
+        }
+        assert_eq!(decoded, [&bytes[..], &bytes[..]].concat());
+        #[cfg(feature = "heapless")]
+        {
+            let mut decoded_heapless = heapless::Vec::<u8, MAX_LEN>::new();
+            unsafe { decoded_heapless.push_hex_decoded_unchecked(&upper) };
+            assert_eq!(bytes, decoded_heapless.as_slice());
+        }
+    }
+}
+
+#[test]
+fn test_push_base64_unchecked() {
+    const MAX_LEN: usize = 100;
+    const CONFIGS: [Base64Config; 4] = [
+        Base64Config::STANDARD,
+        Base64Config::STANDARD_NO_PAD,
+        Base64Config::URL_SAFE,
+        Base64Config::URL_SAFE_NO_PAD,
+    ];
+
+    for (bytes, encoded) in [
+        (&b""[..], ""),
+        (b"f", "Zg=="),
+        (b"fo", "Zm8="),
+        (b"foo", "Zm9v"),
+        (b"foob", "Zm9vYg=="),
+        (b"fooba", "Zm9vYmE="),
+        (b"foobar", "Zm9vYmFy"),
+        (&[0xFB, 0xFF], "+/8="),
+    ] {
+        for config in CONFIGS {
+            let mut expected = String::from(encoded);
+            if config.url_safe {
+                expected = expected.replace('+', "-").replace('/', "_");
+            }
+            if !config.padding {
+                expected = expected.replace('=', "");
+            }
+            assert_eq!(config.encoded_len(bytes.len()), expected.len());
+
+            let mut s = String::with_capacity(expected.len());
+            unsafe { s.push_base64_unchecked(bytes, config) };
+            assert_eq!(s, expected);
+            let mut decoded =
+                Vec::with_capacity(Base64Config::decoded_len_upper_bound(expected.len()));
+            unsafe { decoded.push_base64_decoded_unchecked(&expected, config) };
+            assert_eq!(decoded, bytes);
+        }
+    }
+
+    let mut rng = Pcg64Mcg::new(0xcafe_f00d_d15e_a5e5);
+    for len in 0..MAX_LEN {
+        let bytes: Vec<u8> = (0..len).map(|_| rng.gen()).collect();
+        for config in CONFIGS {
+            let encoded_len = config.encoded_len(len);
+            let mut s = String::with_capacity(encoded_len);
+            unsafe { s.push_base64_unchecked(&bytes, config) };
+            assert_eq!(s.len(), encoded_len);
+            let mut v = Vec::with_capacity(encoded_len);
+            unsafe { v.push_base64_unchecked(&bytes, config) };
+            assert_eq!(v, s.as_bytes());
+            #[cfg(feature = "heapless")]
+            {
+                let mut s_heapless = heapless::String::<{ 4 * MAX_LEN }>::new();
+                unsafe { s_heapless.push_base64_unchecked(&bytes, config) };
+                assert_eq!(s, s_heapless.as_str());
+            }
+
+            let upper_bound = Base64Config::decoded_len_upper_bound(s.len());
+            assert!(upper_bound >= len);
+            if !config.padding {
+                assert_eq!(upper_bound, len);
+            }
+            let mut decoded = Vec::with_capacity(upper_bound);
+            unsafe { decoded.push_base64_decoded_unchecked(&s, config) };
+            assert_eq!(decoded, bytes);
+            #[cfg(feature = "heapless")]
+            {
+                let mut decoded_heapless = heapless::Vec::<u8, { MAX_LEN + 2 }>::new();
+                unsafe { decoded_heapless.push_base64_decoded_unchecked(&s, config) };
+                assert_eq!(bytes, decoded_heapless.as_slice());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_push_escaped_unchecked() {
+    fn json_escaped(string: &str) -> String {
+        let mut res = String::new();
+        for ch in string.chars() {
+            match ch {
+                '"' => res.push_str("\\\""),
+                '\\' => res.push_str("\\\\"),
+                '\n' => res.push_str("\\n"),
+                '\r' => res.push_str("\\r"),
+                '\t' => res.push_str("\\t"),
+                '\x08' => res.push_str("\\b"),
+                '\x0C' => res.push_str("\\f"),
+                '\0'..='\x1F' => write!(res, "\\u{:04x}", ch as u32).unwrap(),
+                _ => res.push(ch),
+            }
+        }
+        res
+    }
+
+    fn html_escaped(string: &str) -> String {
+        string
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+            .replace('\'', "&#39;")
+    }
+
+    const N_STRINGS: usize = 100;
+    const N_CHARS: usize = 50;
+    const SPECIAL: [char; 12] = [
+        '"', '\\', '\n', '\r', '\t', '\x08', '\x0C', '\0', '\x1F', '&', '<', '\'',
+    ];
+    let mut rng = Pcg64Mcg::new(0xcafe_f00d_d15e_a5e5);
+    let mut strings = vec![String::from("\x01\x01"), String::from("\"\"")];
+    for _ in 0..N_STRINGS {
+        let mut string = String::new();
+        for _ in 0..rng.gen_range(0..N_CHARS) {
+            let ch = match rng.gen_range(0..4) {
+                0 => rng.gen(),
+                1 => SPECIAL[rng.gen_range(0..SPECIAL.len())],
+                _ => rng.sample(Alphanumeric) as char,
+            };
+            string.push(ch);
         }
+        strings.push(string);
+    }
+
+    for string in strings {
+        let mut s = String::with_capacity(JSON_ESCAPE_MAX_EXPANSION * string.len());
+        unsafe { s.push_json_escaped_unchecked(&string) };
+        assert_eq!(s, json_escaped(&string));
+        let mut s = String::with_capacity(HTML_ESCAPE_MAX_EXPANSION * string.len());
+        unsafe { s.push_html_escaped_unchecked(&string) };
+        assert_eq!(s, html_escaped(&string));
+        #[cfg(feature = "heapless")]
+        {
+            let mut s_heapless = heapless::String::<{ 2 * 6 * 4 * N_CHARS }>::new();
+            unsafe {
+                s_heapless.push_json_escaped_unchecked(&string);
+                s_heapless.push_html_escaped_unchecked(&string);
+            }
+            assert_eq!(
+                json_escaped(&string) + &html_escaped(&string),
+                s_heapless.as_str()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_push_percent_encoded_unchecked() {
+    const N_STRINGS: usize = 100;
+    const N_CHARS: usize = 50;
+    const SETS: [PercentEncodeSet; 6] = [
+        PercentEncodeSet::CONTROLS,
+        PercentEncodeSet::FRAGMENT,
+        PercentEncodeSet::QUERY,
+        PercentEncodeSet::PATH,
+        PercentEncodeSet::USERINFO,
+        PercentEncodeSet::COMPONENT,
+    ];
+
+    let mut s = String::with_capacity(3 * "a b/c?d=é%".len());
+    unsafe { s.push_percent_encoded_unchecked("a b/c?d=é%", PercentEncodeSet::PATH) };
+    assert_eq!(s, "a%20b/c%3Fd=%C3%A9%");
+    s.clear();
+    unsafe {
+        s.push_percent_encoded_unchecked("a b/c?d=é%", PercentEncodeSet::COMPONENT.remove(b'/'));
+    }
+    assert_eq!(s, "a%20b/c%3Fd%3D%C3%A9%25");
+
+    let mut rng = Pcg64Mcg::new(0xcafe_f00d_d15e_a5e5);
+    for _ in 0..N_STRINGS {
+        let mut string = String::new();
+        for _ in 0..rng.gen_range(0..N_CHARS) {
+            let ch = match rng.gen_range(0..3) {
+                0 => rng.gen(),
+                1 => rng.gen_range('\0'..'\x7F'),
+                _ => rng.sample(Alphanumeric) as char,
+            };
+            string.push(ch);
+        }
+        for set in SETS {
+            let mut expected = String::new();
+            for &byte in string.as_bytes() {
+                if set.contains(byte) {
+                    write!(expected, "%{byte:02X}").unwrap();
+                } else {
+                    expected.push(byte as char);
+                }
+            }
+
+            let mut s = String::with_capacity(3 * string.len());
+            unsafe { s.push_percent_encoded_unchecked(&string, set) };
+            assert_eq!(s, expected);
+            let mut v = Vec::with_capacity(3 * string.len());
+            unsafe { v.push_percent_encoded_unchecked(&string, set) };
+            assert_eq!(v, expected.as_bytes());
+            #[cfg(feature = "heapless")]
+            {
+                let mut s_heapless = heapless::String::<{ 3 * 4 * N_CHARS }>::new();
+                unsafe { s_heapless.push_percent_encoded_unchecked(&string, set) };
+                assert_eq!(s, s_heapless.as_str());
+            }
+
+            if set.contains(b'%') {
+                let mut decoded = String::with_capacity(expected.len());
+                unsafe { decoded.push_percent_decoded_unchecked(&expected) };
+                assert_eq!(decoded, string);
+                #[cfg(feature = "heapless")]
+                {
+                    let mut decoded_heapless = heapless::String::<{ 3 * 4 * N_CHARS }>::new();
+                    unsafe { decoded_heapless.push_percent_decoded_unchecked(&expected) };
+                    assert_eq!(string, decoded_heapless.as_str());
+                }
+            }
+        }
+    }
+
+    let mut v = Vec::with_capacity(16);
+    unsafe { v.push_percent_decoded_unchecked("%ff%2f%2G%%4") };
+    assert_eq!(v, b"\xFF/%2G%%4");
+}
+
+macro_rules! test_push_binary_unchecked_generic {
+    ($($t:ty: $le:ident $be:ident;)*) => ($(
+        let mut rng = Pcg64Mcg::new(0xcafe_f00d_d15e_a5e5);
+        let values: [$t; N_VALUES] = array::from_fn(|_| rng.gen());
+        let mut v = Vec::new();
+        let mut v_unchecked = Vec::with_capacity(2 * N_VALUES * size_of::<$t>());
+        #[cfg(feature = "heapless")]
+        let mut v_heapless = heapless::Vec::<u8, { 2 * N_VALUES * 16 }>::new();
+        for value in values {
+            v.extend_from_slice(&value.to_le_bytes());
+            v.extend_from_slice(&value.to_be_bytes());
+            unsafe {
+                v_unchecked.$le(value);
+                v_unchecked.$be(value);
+            }
+            #[cfg(feature = "heapless")]
+            unsafe {
+                v_heapless.$le(value);
+                v_heapless.$be(value);
+            }
+        }
+        assert_eq!(v, v_unchecked);
+        #[cfg(feature = "heapless")]
+        assert_eq!(v, v_heapless.as_slice());
     )*)
 }
 
 #[test]
-fn test_push_many_unchecked() {
-    const LEN: usize = 100;
-    test_push_many_unchecked_generic! { u8 i8 }
+fn test_push_binary_unchecked() {
+    const N_VALUES: usize = 10;
+    test_push_binary_unchecked_generic! {
+        u16: push_u16_le_unchecked push_u16_be_unchecked;
+        u32: push_u32_le_unchecked push_u32_be_unchecked;
+        u64: push_u64_le_unchecked push_u64_be_unchecked;
+        u128: push_u128_le_unchecked push_u128_be_unchecked;
+        i16: push_i16_le_unchecked push_i16_be_unchecked;
+        i32: push_i32_le_unchecked push_i32_be_unchecked;
+        i64: push_i64_le_unchecked push_i64_be_unchecked;
+        i128: push_i128_le_unchecked push_i128_be_unchecked;
+        f32: push_f32_le_unchecked push_f32_be_unchecked;
+        f64: push_f64_le_unchecked push_f64_be_unchecked;
+    }
+}
+
+macro_rules! test_push_leb128_unchecked_generic {
+    ($($t:ty: $u:ty)*) => ($(
+        assert_eq!(<$u>::MAX_LEN, <$u>::BITS.div_ceil(7) as usize);
+        assert_eq!(<$t>::MAX_LEN, <$t>::BITS.div_ceil(7) as usize);
+
+        let mut rng = Pcg64Mcg::new(0xcafe_f00d_d15e_a5e5);
+        let mut values = vec![0, 1, -1, 63, 64, -64, -65, <$t>::MIN, <$t>::MAX];
+        for _ in 0..N_VALUES {
+            values.push(rng.gen::<$t>() >> rng.gen_range(0..<$t>::BITS));
+        }
+        for value in values {
+            let unsigned = value.cast_unsigned();
+            let zigzag = ((value << 1) ^ (value >> (<$t>::BITS - 1))).cast_unsigned();
+            let mut expected = Vec::new();
+            for mut x in [unsigned, zigzag] {
+                loop {
+                    let byte = x as u8 & 0x7F;
+                    x >>= 7;
+                    if x == 0 {
+                        expected.push(byte);
+                        break;
+                    }
+                    expected.push(byte | 0x80);
+                }
+            }
+            let mut x = value;
+            loop {
+                let byte = x as u8 & 0x7F;
+                x >>= 7;
+                if (x == 0 && byte & 0x40 == 0) || (x == -1 && byte & 0x40 != 0) {
+                    expected.push(byte);
+                    break;
+                }
+                expected.push(byte | 0x80);
+            }
+
+            let mut v = Vec::with_capacity(expected.len());
+            unsafe {
+                v.push_uleb128_unchecked(unsigned);
+                v.push_zigzag_varint_unchecked(value);
+                v.push_sleb128_unchecked(value);
+            }
+            assert_eq!(v, expected);
+            #[cfg(feature = "heapless")]
+            {
+                let mut v_heapless = heapless::Vec::<u8, { 3 * 19 }>::new();
+                unsafe {
+                    v_heapless.push_uleb128_unchecked(unsigned);
+                    v_heapless.push_zigzag_varint_unchecked(value);
+                    v_heapless.push_sleb128_unchecked(value);
+                }
+                assert_eq!(expected, v_heapless.as_slice());
+            }
+        }
+    )*)
+}
+
+#[test]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn test_push_leb128_unchecked() {
+    const N_VALUES: usize = 1000;
+    let mut v = Vec::with_capacity(10);
+    unsafe {
+        v.push_uleb128_unchecked(624_485_u32);
+        v.push_sleb128_unchecked(-123_456_i64);
+        v.push_zigzag_varint_unchecked(-2_i32);
+    }
+    assert_eq!(v, [0xE5, 0x8E, 0x26, 0xC0, 0xBB, 0x78, 3]);
+    test_push_leb128_unchecked_generic! { i8: u8 i16: u16 i32: u32 i64: u64 i128: u128 isize: usize }
+}
+
+#[test]
+#[allow(clippy::cast_possible_truncation)]
+fn test_unchecked_reader() {
+    let mut rng = Pcg64Mcg::new(0xcafe_f00d_d15e_a5e5);
+    let mut values = vec![0, 1, 0x7F, 0x80, u64::MAX];
+    values.extend((0..1000).map(|_| rng.gen::<u64>() >> rng.gen_range(0..64)));
+    let mut bytes = Vec::with_capacity(values.len() * 17);
+    for &value in &values {
+        unsafe {
+            bytes.push_u32_le_unchecked(value as u32);
+            bytes.push_uleb128_unchecked(value);
+            bytes.push_u64_be_unchecked(value);
+        }
+    }
+    bytes.extend_from_slice(b"\x2Aabcdef");
+
+    let mut reader = UncheckedReader::new(&bytes);
+    for &value in &values {
+        unsafe {
+            assert_eq!(reader.read_u32_le_unchecked(), value as u32);
+            assert_eq!(reader.read_uleb128_unchecked::<u64>(), value);
+            assert_eq!(u64::from_be_bytes(reader.read_array_unchecked()), value);
+        }
+    }
+    assert_eq!(reader.len(), 7);
+    unsafe {
+        assert_eq!(reader.read_u8_unchecked(), 0x2A);
+        assert_eq!(reader.read_bytes_unchecked(2), b"ab");
+        reader.skip_unchecked(1);
+        assert_eq!(reader.read_bytes_unchecked(0), b"");
+        assert_eq!(reader.read_array_unchecked::<3>(), *b"def");
+    }
+    assert!(reader.is_empty());
+    assert_eq!(reader.remaining(), b"");
+
+    let mut reader = UncheckedReader::new(&[0xFF, 0x01, 0x80, 0x80, 0x03]);
+    unsafe {
+        assert_eq!(reader.read_uleb128_unchecked::<u8>(), 0xFF);
+        assert_eq!(reader.read_uleb128_unchecked::<u16>(), 3 << 14);
+    }
+}
+
+macro_rules! test_parse_int_unchecked_generic {
+    ($($t:ty: $parse:ident)*) => ($(
+        let mut rng = Pcg64Mcg::new(0xcafe_f00d_d15e_a5e5);
+        let mut values = vec![<$t>::MIN, <$t>::MAX, 0, 1];
+        for _ in 0..N_VALUES {
+            values.push(rng.gen::<$t>() >> rng.gen_range(0..<$t>::BITS));
+        }
+        let mut s = String::new();
+        for value in values {
+            for n_zeros in [0, 1, 8, 17] {
+                s.clear();
+                if value < 0 as $t {
+                    s.push('-');
+                } else if n_zeros == 1 && <$t>::MIN != 0 {
+                    s.push('+');
+                }
+                s.extend(core::iter::repeat('0').take(n_zeros));
+                write!(s, "{}", value.abs_diff(0)).unwrap();
+                assert_eq!(unsafe { $parse(s.as_bytes()) }, value, "{s}");
+            }
+        }
+    )*)
+}
+
+#[test]
+fn test_parse_int_unchecked() {
+    const N_VALUES: usize = 1000;
+    test_parse_int_unchecked_generic! {
+        u8: parse_u8_unchecked u16: parse_u16_unchecked u32: parse_u32_unchecked
+        u64: parse_u64_unchecked u128: parse_u128_unchecked usize: parse_usize_unchecked
+        i8: parse_i8_unchecked i16: parse_i16_unchecked i32: parse_i32_unchecked
+        i64: parse_i64_unchecked i128: parse_i128_unchecked isize: parse_isize_unchecked
+    }
+}
+
+#[test]
+fn test_push_utf16_unchecked() {
+    const N_CHARS: usize = 100;
+    let mut rng = Pcg64Mcg::new(0xcafe_f00d_d15e_a5e5);
+    let chars: [char; N_CHARS] = array::from_fn(|_| match rng.gen_range(0..3) {
+        0 => rng.sample(Alphanumeric) as char,
+        1 => char::from_u32(rng.gen_range(0x80..0xD800)).unwrap(),
+        _ => rng.gen(),
+    });
+    let string: String = chars.iter().collect();
+    let expected: Vec<u16> = string.encode_utf16().collect();
+    assert_eq!(utf16_len(&string), expected.len());
+    assert_eq!(utf16_len(""), 0);
+
+    let mut v = Vec::with_capacity(4 * expected.len());
+    for ch in chars {
+        unsafe { v.push_unchecked(ch) };
+    }
+    unsafe {
+        v.extend_unchecked(chars);
+        v.extend_unchecked(&chars);
+        v.push_str_as_utf16_unchecked(&string);
+    }
+    assert_eq!(v, expected.repeat(4));
+    #[cfg(feature = "heapless")]
+    {
+        let mut v_heapless = heapless::Vec::<u16, { 4 * 2 * N_CHARS }>::new();
+        for ch in chars {
+            unsafe { PushUnchecked::push_unchecked(&mut v_heapless, ch) };
+        }
+        unsafe {
+            v_heapless.extend_unchecked(chars);
+            v_heapless.extend_unchecked(&chars);
+            v_heapless.push_str_as_utf16_unchecked(&string);
+        }
+        assert_eq!(v_heapless.as_slice(), expected.repeat(4));
+    }
+}
+
+#[test]
+fn test_string_push_utf16_unchecked() {
+    const N_CHARS: usize = 100;
+    let mut rng = Pcg64Mcg::new(0xcafe_f00d_d15e_a5e5);
+    let string: String = (0..N_CHARS)
+        .map(|_| match rng.gen_range(0..4) {
+            0 => rng.sample(Alphanumeric) as char,
+            1 => char::from_u32(rng.gen_range(0x80..0x800)).unwrap(),
+            2 => char::from_u32(rng.gen_range(0xE000..0x1_0000)).unwrap(),
+            _ => rng.gen(),
+        })
+        .collect();
+    let utf16: Vec<u16> = string.encode_utf16().collect();
+    assert_eq!(utf8_len_of_utf16(&utf16), string.len());
+
+    let mut s_unchecked = String::with_capacity(string.len());
+    unsafe { s_unchecked.push_utf16_unchecked(&utf16) };
+    assert_eq!(s_unchecked, string);
+    #[cfg(feature = "heapless")]
+    {
+        let mut s_heapless = heapless::String::<{ 4 * N_CHARS }>::new();
+        unsafe { s_heapless.push_utf16_unchecked(&utf16) };
+        assert_eq!(s_heapless, string.as_str());
+    }
+}
+
+#[test]
+fn test_string_push_latin1_unchecked() {
+    const N_BYTES: usize = 200;
+    let mut rng = Pcg64Mcg::new(0xcafe_f00d_d15e_a5e5);
+    for ascii_ratio in [0.0, 0.5, 0.95, 1.0] {
+        let latin1: Vec<u8> = (0..rng.gen_range(0..N_BYTES))
+            .map(|_| {
+                if rng.gen::<f64>() < ascii_ratio {
+                    rng.gen_range(0..0x80)
+                } else {
+                    rng.gen_range(0x80..=0xFF)
+                }
+            })
+            .collect();
+        let expected: String = latin1.iter().map(|&byte| char::from(byte)).collect();
+        assert_eq!(latin1_utf8_len(&latin1), expected.len());
+
+        let mut s_unchecked = String::with_capacity(2 * latin1.len());
+        unsafe { s_unchecked.push_latin1_unchecked(&latin1) };
+        assert_eq!(s_unchecked, expected);
+        #[cfg(feature = "heapless")]
+        {
+            let mut s_heapless = heapless::String::<{ 2 * N_BYTES }>::new();
+            unsafe { s_heapless.push_latin1_unchecked(&latin1) };
+            assert_eq!(s_heapless, expected.as_str());
+        }
+    }
+}
+
+#[test]
+fn test_string_push_ascii_unchecked() {
+    const N_CHARS: usize = 100;
+    let mut rng = Pcg64Mcg::new(0xcafe_f00d_d15e_a5e5);
+    let string: String = (0..N_CHARS)
+        .map(|_| {
+            if rng.gen::<f64>() < 0.75 {
+                char::from(rng.gen_range(0..0x80))
+            } else {
+                rng.gen()
+            }
+        })
+        .collect();
+
+    let mut s_unchecked = String::with_capacity(0x80 + 2 * string.len());
+    for byte in 0..0x80 {
+        unsafe { s_unchecked.push_ascii_unchecked(byte) };
+    }
+    unsafe {
+        s_unchecked.push_str_ascii_lowercase_unchecked(&string);
+        s_unchecked.push_str_ascii_uppercase_unchecked(&string);
+    }
+    let ascii: String = (0..0x80).map(char::from).collect();
+    let expected = ascii + &string.to_ascii_lowercase() + &string.to_ascii_uppercase();
+    assert_eq!(s_unchecked, expected);
+    #[cfg(feature = "heapless")]
+    {
+        let mut s_heapless = heapless::String::<{ 0x80 + 2 * 4 * N_CHARS }>::new();
+        for byte in 0..0x80 {
+            unsafe { s_heapless.push_ascii_unchecked(byte) };
+        }
+        unsafe {
+            s_heapless.push_str_ascii_lowercase_unchecked(&string);
+            s_heapless.push_str_ascii_uppercase_unchecked(&string);
+        }
+        assert_eq!(s_heapless, expected.as_str());
+    }
+}
+
+#[test]
+fn test_string_push_case_mapped_unchecked() {
+    const N_CHARS: usize = 100;
+    for ch in (0..=0x10_FFFF).filter_map(char::from_u32) {
+        let lower_len: usize = ch.to_lowercase().map(char::len_utf8).sum();
+        let upper_len: usize = ch.to_uppercase().map(char::len_utf8).sum();
+        assert!(lower_len <= LOWERCASE_CHAR_MAX_LEN && 2 * lower_len <= 3 * ch.len_utf8());
+        assert!(
+            upper_len <= UPPERCASE_CHAR_MAX_LEN
+                && upper_len <= UPPERCASE_MAX_EXPANSION * ch.len_utf8()
+        );
+    }
+
+    let mut rng = Pcg64Mcg::new(0xcafe_f00d_d15e_a5e5);
+    let chars: [char; N_CHARS] = array::from_fn(|_| match rng.gen_range(0..3) {
+        0 => rng.sample(Alphanumeric) as char,
+        1 => char::from_u32(rng.gen_range(0x80..0x800)).unwrap(),
+        _ => rng.gen(),
+    });
+    let string: String = chars.iter().collect();
+    let lowercase: String = chars.iter().flat_map(|ch| ch.to_lowercase()).collect();
+    let uppercase = string.to_uppercase();
+
+    let mut s_unchecked = String::with_capacity(
+        (LOWERCASE_MAX_EXPANSION + UPPERCASE_MAX_EXPANSION) * 2 * string.len(),
+    );
+    unsafe {
+        for ch in chars {
+            s_unchecked.push_lowercase_unchecked(ch);
+        }
+        s_unchecked.push_str_lowercase_unchecked(&string);
+        for ch in chars {
+            s_unchecked.push_uppercase_unchecked(ch);
+        }
+        s_unchecked.push_str_uppercase_unchecked(&string);
+    }
+    let expected = lowercase.repeat(2) + &uppercase.repeat(2);
+    assert_eq!(s_unchecked, expected);
+    #[cfg(feature = "heapless")]
+    {
+        let mut s_heapless = heapless::String::<{ 4 * 3 * 4 * N_CHARS }>::new();
+        unsafe {
+            s_heapless.push_str_lowercase_unchecked(&string);
+            s_heapless.push_str_uppercase_unchecked(&string);
+        }
+        assert_eq!(s_heapless, (lowercase + &uppercase).as_str());
+    }
+}
+
+#[test]
+fn test_unchecked_writer() {
+    let name = "Rust";
+    let expected = format!("Hello, {name}! {:>8.3}|{:#x}|{}", 1.5_f64, 255, 'é');
+    let mut s = String::with_capacity(expected.len());
+    let mut writer = unsafe { UncheckedWriter::new(&mut s, expected.len()) };
+    write!(writer, "Hello, {name}! {:>8.3}|{:#x}|", 1.5_f64, 255).unwrap();
+    assert_eq!(writer.remaining(), 'é'.len_utf8());
+    writer.write_char('é').unwrap();
+    assert_eq!(writer.remaining(), 0);
+    assert_eq!(s, expected);
+    #[cfg(feature = "heapless")]
+    {
+        let mut s_heapless = heapless::String::<64>::new();
+        s_heapless.push_str("> ").unwrap();
+        let mut writer = unsafe { UncheckedWriter::new(&mut s_heapless, expected.len()) };
+        write!(writer, "{expected}").unwrap();
+        assert_eq!(s_heapless, format!("> {expected}").as_str());
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_unchecked_io_writer() {
+    use std::io::IoSlice;
+
+    fn write_record(w: &mut impl std::io::Write) -> std::io::Result<()> {
+        w.write_all(b"id=")?;
+        write!(w, "{}", 42)?;
+        assert_eq!(
+            w.write_vectored(&[IoSlice::new(b";name="), IoSlice::new(b"Rust")])?,
+            10
+        );
+        assert_eq!(w.write(b"\n")?, 1);
+        w.flush()
+    }
+
+    let mut expected = Vec::new();
+    write_record(&mut expected).unwrap();
+
+    let mut v = Vec::with_capacity(expected.len());
+    let mut writer = unsafe { UncheckedIoWriter::new(&mut v, expected.len()) };
+    write_record(&mut writer).unwrap();
+    assert_eq!(writer.remaining(), 0);
+    assert_eq!(v, expected);
+    #[cfg(feature = "heapless")]
+    {
+        let mut v_heapless = heapless::Vec::<u8, 64>::new();
+        let mut writer = unsafe { UncheckedIoWriter::new(&mut v_heapless, expected.len()) };
+        write_record(&mut writer).unwrap();
+        assert_eq!(v_heapless, expected.as_slice());
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_os_string_and_path_buf_push_unchecked() {
+    use std::{
+        ffi::{OsStr, OsString},
+        path::{PathBuf, MAIN_SEPARATOR_STR},
+    };
+
+    let mut s = OsString::with_capacity(10);
+    unsafe {
+        s.push_unchecked(OsStr::new("hello"));
+        s.push_unchecked(OsStr::new(""));
+        s.push_unchecked(OsStr::new(", 世"));
+    }
+    assert_eq!(s, "hello, 世");
+
+    for root in ["", "root", "root/dir", "/", "root/"] {
+        let mut expected = PathBuf::from(root);
+        expected.push("name");
+        expected.push("file.txt");
+
+        let mut path = PathBuf::from(root);
+        path.reserve_exact(2 * MAIN_SEPARATOR_STR.len() + "name".len() + "file.txt".len());
+        unsafe {
+            path.push_component_unchecked("name");
+            path.push_component_unchecked(OsStr::new("file.txt"));
+        }
+        assert_eq!(path, expected);
+        assert_eq!(path.as_os_str(), expected.as_os_str());
+    }
 }